@@ -20,6 +20,7 @@ typedef enum {
     BLS_OK = 0,
     BLS_INVALID_BYTES = 1,
     BLS_VERIFICATION_FAILED = 2,
+    BLS_UNKNOWN_ERROR = 3,
 } bls_sign_status;
 
 void generate_keys(const uint8_t *sk_ptr,
@@ -155,6 +155,9 @@ func Sign(sk, pk, msg []byte) (
 	if err != nil {
 		return nil, err
 	}
+	if e, ok := sig.GetSig().(*SignResponse_Error); ok {
+		return nil, fmt.Errorf("sign: %s", e.Error)
+	}
 	sign := sig.GetSignature()
 	return sign, nil
 }
@@ -172,10 +175,16 @@ func Verify(apk, sig, msg []byte) (err error) {
 			Message:   msg,
 		},
 	)
+	if err != nil {
+		return err
+	}
+	if e, ok := vr.GetVer().(*VerifyResponse_Error); ok {
+		return fmt.Errorf("verify: %s", e.Error)
+	}
 	if !vr.GetValid() {
 		return errors.New("invalid signature")
 	}
-	return err
+	return nil
 }
 
 func CreateApk(pk []byte) (apk []byte, err error) {
@@ -189,6 +198,12 @@ func CreateApk(pk []byte) (apk []byte, err error) {
 			PublicKey: pk,
 		},
 	)
+	if err != nil {
+		return nil, err
+	}
+	if e, ok := a.GetApk().(*CreateAPKResponse_Error); ok {
+		return nil, fmt.Errorf("create apk: %s", e.Error)
+	}
 	apk = a.GetAPK()
 	return
 }
@@ -208,6 +223,12 @@ func AggregatePk(apk []byte, pks ...[]byte) (
 			Keys: pks,
 		},
 	)
+	if err != nil {
+		return nil, err
+	}
+	if e, ok := a.GetAgg().(*AggregateResponse_Error); ok {
+		return nil, fmt.Errorf("aggregate pk: %s", e.Error)
+	}
 	newApk = a.GetCode()
 	return
 }
@@ -227,6 +248,12 @@ func AggregateSig(sig []byte, sigs ...[]byte) (
 			Signatures: sigs,
 		},
 	)
+	if err != nil {
+		return nil, err
+	}
+	if e, ok := a.GetAgg().(*AggregateResponse_Error); ok {
+		return nil, fmt.Errorf("aggregate sig: %s", e.Error)
+	}
 	aggregatedSig = a.GetCode()
 	return
 }
@@ -7,7 +7,7 @@
 // Code generated by protoc-gen-go. DO NOT EDIT.
 // versions:
 // 	protoc-gen-go v1.27.1
-// 	protoc        v3.14.0
+// 	protoc        v3.15.8
 // source: bls12381sig.proto
 
 package bls
@@ -30,25 +30,73 @@ const (
 	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
 )
 
+// Errors are returned in the `Error` branch of the responses, rather than as
+// a gRPC status. The errors of the library map to them as follows:
+//
+//   BytesError          -> BLS_INVALID_BYTES
+//   InvalidSignature    -> BLS_VERIFICATION_FAILED
+//   InvalidThreshold    -> BLS_INVALID_THRESHOLD
+//   InvalidShares       -> BLS_INVALID_SHARES
+//   InvalidCiphertext   -> BLS_INVALID_CIPHERTEXT
+//   NotYetValid         -> BLS_NOT_YET_VALID
+//   Expired             -> BLS_EXPIRED
+//   Replay              -> BLS_REPLAY
+//   DoubleSign          -> BLS_DOUBLE_SIGN
+//   Storage             -> BLS_STORAGE
+//   InvalidInterchange  -> BLS_INVALID_INTERCHANGE
+//   any other error     -> UNKNOWN
+//
+// BLS_INVALID_BYTES is also returned for fields of the wrong length, and
+// UNKNOWN_KEY_ID for a KeyId that doesn't match any key held by the service.
 type Error int32
 
 const (
 	Error_BLS_INVALID_BYTES       Error = 0
 	Error_BLS_VERIFICATION_FAILED Error = 1
 	Error_UNKNOWN                 Error = 2
+	Error_BLS_INVALID_THRESHOLD   Error = 3
+	Error_BLS_INVALID_SHARES      Error = 4
+	Error_BLS_INVALID_CIPHERTEXT  Error = 5
+	Error_BLS_NOT_YET_VALID       Error = 6
+	Error_BLS_EXPIRED             Error = 7
+	Error_BLS_REPLAY              Error = 8
+	Error_BLS_DOUBLE_SIGN         Error = 9
+	Error_BLS_STORAGE             Error = 10
+	Error_BLS_INVALID_INTERCHANGE Error = 11
+	Error_UNKNOWN_KEY_ID          Error = 12
 )
 
 // Enum value maps for Error.
 var (
 	Error_name = map[int32]string{
-		0: "BLS_INVALID_BYTES",
-		1: "BLS_VERIFICATION_FAILED",
-		2: "UNKNOWN",
+		0:  "BLS_INVALID_BYTES",
+		1:  "BLS_VERIFICATION_FAILED",
+		2:  "UNKNOWN",
+		3:  "BLS_INVALID_THRESHOLD",
+		4:  "BLS_INVALID_SHARES",
+		5:  "BLS_INVALID_CIPHERTEXT",
+		6:  "BLS_NOT_YET_VALID",
+		7:  "BLS_EXPIRED",
+		8:  "BLS_REPLAY",
+		9:  "BLS_DOUBLE_SIGN",
+		10: "BLS_STORAGE",
+		11: "BLS_INVALID_INTERCHANGE",
+		12: "UNKNOWN_KEY_ID",
 	}
 	Error_value = map[string]int32{
 		"BLS_INVALID_BYTES":       0,
 		"BLS_VERIFICATION_FAILED": 1,
 		"UNKNOWN":                 2,
+		"BLS_INVALID_THRESHOLD":   3,
+		"BLS_INVALID_SHARES":      4,
+		"BLS_INVALID_CIPHERTEXT":  5,
+		"BLS_NOT_YET_VALID":       6,
+		"BLS_EXPIRED":             7,
+		"BLS_REPLAY":              8,
+		"BLS_DOUBLE_SIGN":         9,
+		"BLS_STORAGE":             10,
+		"BLS_INVALID_INTERCHANGE": 11,
+		"UNKNOWN_KEY_ID":          12,
 	}
 )
 
@@ -315,6 +363,147 @@ func (*SignResponse_Signature) isSignResponse_Sig() {}
 
 func (*SignResponse_Error) isSignResponse_Sig() {}
 
+// KeyId is the PublicKey of a key pair held by the service
+type SignWithKeyIdRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	KeyId   []byte `protobuf:"bytes,1,opt,name=KeyId,proto3" json:"KeyId,omitempty"`
+	Message []byte `protobuf:"bytes,2,opt,name=Message,proto3" json:"Message,omitempty"`
+}
+
+func (x *SignWithKeyIdRequest) Reset() {
+	*x = SignWithKeyIdRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_bls12381sig_proto_msgTypes[4]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SignWithKeyIdRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SignWithKeyIdRequest) ProtoMessage() {}
+
+func (x *SignWithKeyIdRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_bls12381sig_proto_msgTypes[4]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SignWithKeyIdRequest.ProtoReflect.Descriptor instead.
+func (*SignWithKeyIdRequest) Descriptor() ([]byte, []int) {
+	return file_bls12381sig_proto_rawDescGZIP(), []int{4}
+}
+
+func (x *SignWithKeyIdRequest) GetKeyId() []byte {
+	if x != nil {
+		return x.KeyId
+	}
+	return nil
+}
+
+func (x *SignWithKeyIdRequest) GetMessage() []byte {
+	if x != nil {
+		return x.Message
+	}
+	return nil
+}
+
+type ListKeysRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *ListKeysRequest) Reset() {
+	*x = ListKeysRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_bls12381sig_proto_msgTypes[5]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListKeysRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListKeysRequest) ProtoMessage() {}
+
+func (x *ListKeysRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_bls12381sig_proto_msgTypes[5]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListKeysRequest.ProtoReflect.Descriptor instead.
+func (*ListKeysRequest) Descriptor() ([]byte, []int) {
+	return file_bls12381sig_proto_rawDescGZIP(), []int{5}
+}
+
+type ListKeysResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	PublicKeys [][]byte `protobuf:"bytes,1,rep,name=PublicKeys,proto3" json:"PublicKeys,omitempty"`
+}
+
+func (x *ListKeysResponse) Reset() {
+	*x = ListKeysResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_bls12381sig_proto_msgTypes[6]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ListKeysResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ListKeysResponse) ProtoMessage() {}
+
+func (x *ListKeysResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_bls12381sig_proto_msgTypes[6]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ListKeysResponse.ProtoReflect.Descriptor instead.
+func (*ListKeysResponse) Descriptor() ([]byte, []int) {
+	return file_bls12381sig_proto_rawDescGZIP(), []int{6}
+}
+
+func (x *ListKeysResponse) GetPublicKeys() [][]byte {
+	if x != nil {
+		return x.PublicKeys
+	}
+	return nil
+}
+
 type VerifyRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
@@ -328,7 +517,7 @@ type VerifyRequest struct {
 func (x *VerifyRequest) Reset() {
 	*x = VerifyRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_bls12381sig_proto_msgTypes[4]
+		mi := &file_bls12381sig_proto_msgTypes[7]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -341,7 +530,7 @@ func (x *VerifyRequest) String() string {
 func (*VerifyRequest) ProtoMessage() {}
 
 func (x *VerifyRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_bls12381sig_proto_msgTypes[4]
+	mi := &file_bls12381sig_proto_msgTypes[7]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -354,7 +543,7 @@ func (x *VerifyRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use VerifyRequest.ProtoReflect.Descriptor instead.
 func (*VerifyRequest) Descriptor() ([]byte, []int) {
-	return file_bls12381sig_proto_rawDescGZIP(), []int{4}
+	return file_bls12381sig_proto_rawDescGZIP(), []int{7}
 }
 
 func (x *VerifyRequest) GetApk() []byte {
@@ -378,6 +567,8 @@ func (x *VerifyRequest) GetMessage() []byte {
 	return nil
 }
 
+// Valid is only ever true: a signature that doesn't verify is reported as
+// BLS_VERIFICATION_FAILED
 type VerifyResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
@@ -392,7 +583,7 @@ type VerifyResponse struct {
 func (x *VerifyResponse) Reset() {
 	*x = VerifyResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_bls12381sig_proto_msgTypes[5]
+		mi := &file_bls12381sig_proto_msgTypes[8]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -405,7 +596,7 @@ func (x *VerifyResponse) String() string {
 func (*VerifyResponse) ProtoMessage() {}
 
 func (x *VerifyResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_bls12381sig_proto_msgTypes[5]
+	mi := &file_bls12381sig_proto_msgTypes[8]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -418,7 +609,7 @@ func (x *VerifyResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use VerifyResponse.ProtoReflect.Descriptor instead.
 func (*VerifyResponse) Descriptor() ([]byte, []int) {
-	return file_bls12381sig_proto_rawDescGZIP(), []int{5}
+	return file_bls12381sig_proto_rawDescGZIP(), []int{8}
 }
 
 func (m *VerifyResponse) GetVer() isVerifyResponse_Ver {
@@ -458,31 +649,32 @@ func (*VerifyResponse_Valid) isVerifyResponse_Ver() {}
 
 func (*VerifyResponse_Error) isVerifyResponse_Ver() {}
 
-type CreateAPKRequest struct {
+// Results are in the same order as the items of the request
+type BatchVerifyRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	PublicKey []byte `protobuf:"bytes,1,opt,name=PublicKey,proto3" json:"PublicKey,omitempty"`
+	Items []*VerifyRequest `protobuf:"bytes,1,rep,name=Items,proto3" json:"Items,omitempty"`
 }
 
-func (x *CreateAPKRequest) Reset() {
-	*x = CreateAPKRequest{}
+func (x *BatchVerifyRequest) Reset() {
+	*x = BatchVerifyRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_bls12381sig_proto_msgTypes[6]
+		mi := &file_bls12381sig_proto_msgTypes[9]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *CreateAPKRequest) String() string {
+func (x *BatchVerifyRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CreateAPKRequest) ProtoMessage() {}
+func (*BatchVerifyRequest) ProtoMessage() {}
 
-func (x *CreateAPKRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_bls12381sig_proto_msgTypes[6]
+func (x *BatchVerifyRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_bls12381sig_proto_msgTypes[9]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -493,46 +685,43 @@ func (x *CreateAPKRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CreateAPKRequest.ProtoReflect.Descriptor instead.
-func (*CreateAPKRequest) Descriptor() ([]byte, []int) {
-	return file_bls12381sig_proto_rawDescGZIP(), []int{6}
+// Deprecated: Use BatchVerifyRequest.ProtoReflect.Descriptor instead.
+func (*BatchVerifyRequest) Descriptor() ([]byte, []int) {
+	return file_bls12381sig_proto_rawDescGZIP(), []int{9}
 }
 
-func (x *CreateAPKRequest) GetPublicKey() []byte {
+func (x *BatchVerifyRequest) GetItems() []*VerifyRequest {
 	if x != nil {
-		return x.PublicKey
+		return x.Items
 	}
 	return nil
 }
 
-type CreateAPKResponse struct {
+type BatchVerifyResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	// Types that are assignable to Apk:
-	//	*CreateAPKResponse_APK
-	//	*CreateAPKResponse_Error
-	Apk isCreateAPKResponse_Apk `protobuf_oneof:"apk"`
+	Results []*VerifyResponse `protobuf:"bytes,1,rep,name=Results,proto3" json:"Results,omitempty"`
 }
 
-func (x *CreateAPKResponse) Reset() {
-	*x = CreateAPKResponse{}
+func (x *BatchVerifyResponse) Reset() {
+	*x = BatchVerifyResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_bls12381sig_proto_msgTypes[7]
+		mi := &file_bls12381sig_proto_msgTypes[10]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *CreateAPKResponse) String() string {
+func (x *BatchVerifyResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CreateAPKResponse) ProtoMessage() {}
+func (*BatchVerifyResponse) ProtoMessage() {}
 
-func (x *CreateAPKResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_bls12381sig_proto_msgTypes[7]
+func (x *BatchVerifyResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_bls12381sig_proto_msgTypes[10]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -543,74 +732,92 @@ func (x *CreateAPKResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CreateAPKResponse.ProtoReflect.Descriptor instead.
-func (*CreateAPKResponse) Descriptor() ([]byte, []int) {
-	return file_bls12381sig_proto_rawDescGZIP(), []int{7}
+// Deprecated: Use BatchVerifyResponse.ProtoReflect.Descriptor instead.
+func (*BatchVerifyResponse) Descriptor() ([]byte, []int) {
+	return file_bls12381sig_proto_rawDescGZIP(), []int{10}
 }
 
-func (m *CreateAPKResponse) GetApk() isCreateAPKResponse_Apk {
-	if m != nil {
-		return m.Apk
+func (x *BatchVerifyResponse) GetResults() []*VerifyResponse {
+	if x != nil {
+		return x.Results
 	}
 	return nil
 }
 
-func (x *CreateAPKResponse) GetAPK() []byte {
-	if x, ok := x.GetApk().(*CreateAPKResponse_APK); ok {
-		return x.APK
-	}
-	return nil
+// Results are in the same order as the items of the request, each signed with
+// a key pair held by the service, so that secret keys never go over the wire
+type BatchSignRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Items []*SignWithKeyIdRequest `protobuf:"bytes,1,rep,name=Items,proto3" json:"Items,omitempty"`
 }
 
-func (x *CreateAPKResponse) GetError() Error {
-	if x, ok := x.GetApk().(*CreateAPKResponse_Error); ok {
-		return x.Error
+func (x *BatchSignRequest) Reset() {
+	*x = BatchSignRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_bls12381sig_proto_msgTypes[11]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
 	}
-	return Error_BLS_INVALID_BYTES
 }
 
-type isCreateAPKResponse_Apk interface {
-	isCreateAPKResponse_Apk()
+func (x *BatchSignRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
 }
 
-type CreateAPKResponse_APK struct {
-	APK []byte `protobuf:"bytes,1,opt,name=APK,proto3,oneof"`
-}
+func (*BatchSignRequest) ProtoMessage() {}
 
-type CreateAPKResponse_Error struct {
-	Error Error `protobuf:"varint,2,opt,name=Error,proto3,enum=signer.Error,oneof"`
+func (x *BatchSignRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_bls12381sig_proto_msgTypes[11]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
 }
 
-func (*CreateAPKResponse_APK) isCreateAPKResponse_Apk() {}
+// Deprecated: Use BatchSignRequest.ProtoReflect.Descriptor instead.
+func (*BatchSignRequest) Descriptor() ([]byte, []int) {
+	return file_bls12381sig_proto_rawDescGZIP(), []int{11}
+}
 
-func (*CreateAPKResponse_Error) isCreateAPKResponse_Apk() {}
+func (x *BatchSignRequest) GetItems() []*SignWithKeyIdRequest {
+	if x != nil {
+		return x.Items
+	}
+	return nil
+}
 
-type AggregatePKRequest struct {
+type BatchSignResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	APK  []byte   `protobuf:"bytes,1,opt,name=APK,proto3" json:"APK,omitempty"`
-	Keys [][]byte `protobuf:"bytes,2,rep,name=Keys,proto3" json:"Keys,omitempty"`
+	Results []*SignResponse `protobuf:"bytes,1,rep,name=Results,proto3" json:"Results,omitempty"`
 }
 
-func (x *AggregatePKRequest) Reset() {
-	*x = AggregatePKRequest{}
+func (x *BatchSignResponse) Reset() {
+	*x = BatchSignResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_bls12381sig_proto_msgTypes[8]
+		mi := &file_bls12381sig_proto_msgTypes[12]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *AggregatePKRequest) String() string {
+func (x *BatchSignResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AggregatePKRequest) ProtoMessage() {}
+func (*BatchSignResponse) ProtoMessage() {}
 
-func (x *AggregatePKRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_bls12381sig_proto_msgTypes[8]
+func (x *BatchSignResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_bls12381sig_proto_msgTypes[12]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -621,53 +828,43 @@ func (x *AggregatePKRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AggregatePKRequest.ProtoReflect.Descriptor instead.
-func (*AggregatePKRequest) Descriptor() ([]byte, []int) {
-	return file_bls12381sig_proto_rawDescGZIP(), []int{8}
-}
-
-func (x *AggregatePKRequest) GetAPK() []byte {
-	if x != nil {
-		return x.APK
-	}
-	return nil
+// Deprecated: Use BatchSignResponse.ProtoReflect.Descriptor instead.
+func (*BatchSignResponse) Descriptor() ([]byte, []int) {
+	return file_bls12381sig_proto_rawDescGZIP(), []int{12}
 }
 
-func (x *AggregatePKRequest) GetKeys() [][]byte {
+func (x *BatchSignResponse) GetResults() []*SignResponse {
 	if x != nil {
-		return x.Keys
+		return x.Results
 	}
 	return nil
 }
 
-type AggregateResponse struct {
+type CreateAPKRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	// Types that are assignable to Agg:
-	//	*AggregateResponse_Code
-	//	*AggregateResponse_Error
-	Agg isAggregateResponse_Agg `protobuf_oneof:"agg"`
+	PublicKey []byte `protobuf:"bytes,1,opt,name=PublicKey,proto3" json:"PublicKey,omitempty"`
 }
 
-func (x *AggregateResponse) Reset() {
-	*x = AggregateResponse{}
+func (x *CreateAPKRequest) Reset() {
+	*x = CreateAPKRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_bls12381sig_proto_msgTypes[9]
+		mi := &file_bls12381sig_proto_msgTypes[13]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *AggregateResponse) String() string {
+func (x *CreateAPKRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*AggregateResponse) ProtoMessage() {}
+func (*CreateAPKRequest) ProtoMessage() {}
 
-func (x *AggregateResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_bls12381sig_proto_msgTypes[9]
+func (x *CreateAPKRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_bls12381sig_proto_msgTypes[13]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -678,47 +875,152 @@ func (x *AggregateResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use AggregateResponse.ProtoReflect.Descriptor instead.
-func (*AggregateResponse) Descriptor() ([]byte, []int) {
-	return file_bls12381sig_proto_rawDescGZIP(), []int{9}
+// Deprecated: Use CreateAPKRequest.ProtoReflect.Descriptor instead.
+func (*CreateAPKRequest) Descriptor() ([]byte, []int) {
+	return file_bls12381sig_proto_rawDescGZIP(), []int{13}
 }
 
-func (m *AggregateResponse) GetAgg() isAggregateResponse_Agg {
-	if m != nil {
-		return m.Agg
+func (x *CreateAPKRequest) GetPublicKey() []byte {
+	if x != nil {
+		return x.PublicKey
 	}
 	return nil
 }
 
-func (x *AggregateResponse) GetCode() []byte {
-	if x, ok := x.GetAgg().(*AggregateResponse_Code); ok {
-		return x.Code
-	}
+type CreateAPKResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Types that are assignable to Apk:
+	//	*CreateAPKResponse_APK
+	//	*CreateAPKResponse_Error
+	Apk isCreateAPKResponse_Apk `protobuf_oneof:"apk"`
+}
+
+func (x *CreateAPKResponse) Reset() {
+	*x = CreateAPKResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_bls12381sig_proto_msgTypes[14]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CreateAPKResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CreateAPKResponse) ProtoMessage() {}
+
+func (x *CreateAPKResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_bls12381sig_proto_msgTypes[14]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CreateAPKResponse.ProtoReflect.Descriptor instead.
+func (*CreateAPKResponse) Descriptor() ([]byte, []int) {
+	return file_bls12381sig_proto_rawDescGZIP(), []int{14}
+}
+
+func (m *CreateAPKResponse) GetApk() isCreateAPKResponse_Apk {
+	if m != nil {
+		return m.Apk
+	}
 	return nil
 }
 
-func (x *AggregateResponse) GetError() Error {
-	if x, ok := x.GetAgg().(*AggregateResponse_Error); ok {
+func (x *CreateAPKResponse) GetAPK() []byte {
+	if x, ok := x.GetApk().(*CreateAPKResponse_APK); ok {
+		return x.APK
+	}
+	return nil
+}
+
+func (x *CreateAPKResponse) GetError() Error {
+	if x, ok := x.GetApk().(*CreateAPKResponse_Error); ok {
 		return x.Error
 	}
 	return Error_BLS_INVALID_BYTES
 }
 
-type isAggregateResponse_Agg interface {
-	isAggregateResponse_Agg()
+type isCreateAPKResponse_Apk interface {
+	isCreateAPKResponse_Apk()
 }
 
-type AggregateResponse_Code struct {
-	Code []byte `protobuf:"bytes,1,opt,name=Code,proto3,oneof"`
+type CreateAPKResponse_APK struct {
+	APK []byte `protobuf:"bytes,1,opt,name=APK,proto3,oneof"`
 }
 
-type AggregateResponse_Error struct {
+type CreateAPKResponse_Error struct {
 	Error Error `protobuf:"varint,2,opt,name=Error,proto3,enum=signer.Error,oneof"`
 }
 
-func (*AggregateResponse_Code) isAggregateResponse_Agg() {}
+func (*CreateAPKResponse_APK) isCreateAPKResponse_Apk() {}
 
-func (*AggregateResponse_Error) isAggregateResponse_Agg() {}
+func (*CreateAPKResponse_Error) isCreateAPKResponse_Apk() {}
+
+type AggregatePKRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	APK  []byte   `protobuf:"bytes,1,opt,name=APK,proto3" json:"APK,omitempty"`
+	Keys [][]byte `protobuf:"bytes,2,rep,name=Keys,proto3" json:"Keys,omitempty"`
+}
+
+func (x *AggregatePKRequest) Reset() {
+	*x = AggregatePKRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_bls12381sig_proto_msgTypes[15]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AggregatePKRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AggregatePKRequest) ProtoMessage() {}
+
+func (x *AggregatePKRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_bls12381sig_proto_msgTypes[15]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AggregatePKRequest.ProtoReflect.Descriptor instead.
+func (*AggregatePKRequest) Descriptor() ([]byte, []int) {
+	return file_bls12381sig_proto_rawDescGZIP(), []int{15}
+}
+
+func (x *AggregatePKRequest) GetAPK() []byte {
+	if x != nil {
+		return x.APK
+	}
+	return nil
+}
+
+func (x *AggregatePKRequest) GetKeys() [][]byte {
+	if x != nil {
+		return x.Keys
+	}
+	return nil
+}
 
 type AggregateSigRequest struct {
 	state         protoimpl.MessageState
@@ -732,7 +1034,7 @@ type AggregateSigRequest struct {
 func (x *AggregateSigRequest) Reset() {
 	*x = AggregateSigRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_bls12381sig_proto_msgTypes[10]
+		mi := &file_bls12381sig_proto_msgTypes[16]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -745,7 +1047,7 @@ func (x *AggregateSigRequest) String() string {
 func (*AggregateSigRequest) ProtoMessage() {}
 
 func (x *AggregateSigRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_bls12381sig_proto_msgTypes[10]
+	mi := &file_bls12381sig_proto_msgTypes[16]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -758,7 +1060,7 @@ func (x *AggregateSigRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use AggregateSigRequest.ProtoReflect.Descriptor instead.
 func (*AggregateSigRequest) Descriptor() ([]byte, []int) {
-	return file_bls12381sig_proto_rawDescGZIP(), []int{10}
+	return file_bls12381sig_proto_rawDescGZIP(), []int{16}
 }
 
 func (x *AggregateSigRequest) GetSignature() []byte {
@@ -775,6 +1077,86 @@ func (x *AggregateSigRequest) GetSignatures() [][]byte {
 	return nil
 }
 
+type AggregateResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Types that are assignable to Agg:
+	//	*AggregateResponse_Code
+	//	*AggregateResponse_Error
+	Agg isAggregateResponse_Agg `protobuf_oneof:"agg"`
+}
+
+func (x *AggregateResponse) Reset() {
+	*x = AggregateResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_bls12381sig_proto_msgTypes[17]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AggregateResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AggregateResponse) ProtoMessage() {}
+
+func (x *AggregateResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_bls12381sig_proto_msgTypes[17]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AggregateResponse.ProtoReflect.Descriptor instead.
+func (*AggregateResponse) Descriptor() ([]byte, []int) {
+	return file_bls12381sig_proto_rawDescGZIP(), []int{17}
+}
+
+func (m *AggregateResponse) GetAgg() isAggregateResponse_Agg {
+	if m != nil {
+		return m.Agg
+	}
+	return nil
+}
+
+func (x *AggregateResponse) GetCode() []byte {
+	if x, ok := x.GetAgg().(*AggregateResponse_Code); ok {
+		return x.Code
+	}
+	return nil
+}
+
+func (x *AggregateResponse) GetError() Error {
+	if x, ok := x.GetAgg().(*AggregateResponse_Error); ok {
+		return x.Error
+	}
+	return Error_BLS_INVALID_BYTES
+}
+
+type isAggregateResponse_Agg interface {
+	isAggregateResponse_Agg()
+}
+
+type AggregateResponse_Code struct {
+	Code []byte `protobuf:"bytes,1,opt,name=Code,proto3,oneof"`
+}
+
+type AggregateResponse_Error struct {
+	Error Error `protobuf:"varint,2,opt,name=Error,proto3,enum=signer.Error,oneof"`
+}
+
+func (*AggregateResponse_Code) isAggregateResponse_Agg() {}
+
+func (*AggregateResponse_Error) isAggregateResponse_Agg() {}
+
 var File_bls12381sig_proto protoreflect.FileDescriptor
 
 var file_bls12381sig_proto_rawDesc = []byte{
@@ -798,76 +1180,136 @@ var file_bls12381sig_proto_rawDesc = []byte{
 	0x52, 0x09, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x12, 0x25, 0x0a, 0x05, 0x45,
 	0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0d, 0x2e, 0x73, 0x69, 0x67,
 	0x6e, 0x65, 0x72, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x45, 0x72, 0x72,
-	0x6f, 0x72, 0x42, 0x05, 0x0a, 0x03, 0x73, 0x69, 0x67, 0x22, 0x59, 0x0a, 0x0d, 0x56, 0x65, 0x72,
-	0x69, 0x66, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x41, 0x70,
-	0x6b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x41, 0x70, 0x6b, 0x12, 0x1c, 0x0a, 0x09,
-	0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52,
-	0x09, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x4d, 0x65,
-	0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x4d, 0x65, 0x73,
-	0x73, 0x61, 0x67, 0x65, 0x22, 0x56, 0x0a, 0x0e, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x16, 0x0a, 0x05, 0x56, 0x61, 0x6c, 0x69, 0x64, 0x18,
-	0x01, 0x20, 0x01, 0x28, 0x08, 0x48, 0x00, 0x52, 0x05, 0x56, 0x61, 0x6c, 0x69, 0x64, 0x12, 0x25,
-	0x0a, 0x05, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0d, 0x2e,
-	0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05,
-	0x45, 0x72, 0x72, 0x6f, 0x72, 0x42, 0x05, 0x0a, 0x03, 0x76, 0x65, 0x72, 0x22, 0x30, 0x0a, 0x10,
-	0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x41, 0x50, 0x4b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-	0x12, 0x1c, 0x0a, 0x09, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x18, 0x01, 0x20,
-	0x01, 0x28, 0x0c, 0x52, 0x09, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x22, 0x55,
-	0x0a, 0x11, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x41, 0x50, 0x4b, 0x52, 0x65, 0x73, 0x70, 0x6f,
-	0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x03, 0x41, 0x50, 0x4b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c,
-	0x48, 0x00, 0x52, 0x03, 0x41, 0x50, 0x4b, 0x12, 0x25, 0x0a, 0x05, 0x45, 0x72, 0x72, 0x6f, 0x72,
-	0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0d, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e,
-	0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x42, 0x05,
-	0x0a, 0x03, 0x61, 0x70, 0x6b, 0x22, 0x3a, 0x0a, 0x12, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61,
-	0x74, 0x65, 0x50, 0x4b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x41,
-	0x50, 0x4b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x41, 0x50, 0x4b, 0x12, 0x12, 0x0a,
-	0x04, 0x4b, 0x65, 0x79, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0c, 0x52, 0x04, 0x4b, 0x65, 0x79,
-	0x73, 0x22, 0x57, 0x0a, 0x11, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x0c, 0x48, 0x00, 0x52, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x12, 0x25, 0x0a, 0x05,
-	0x45, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0d, 0x2e, 0x73, 0x69,
-	0x67, 0x6e, 0x65, 0x72, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x45, 0x72,
-	0x72, 0x6f, 0x72, 0x42, 0x05, 0x0a, 0x03, 0x61, 0x67, 0x67, 0x22, 0x53, 0x0a, 0x13, 0x41, 0x67,
-	0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x53, 0x69, 0x67, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
-	0x74, 0x12, 0x1c, 0x0a, 0x09, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x12,
-	0x1e, 0x0a, 0x0a, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x73, 0x18, 0x02, 0x20,
-	0x03, 0x28, 0x0c, 0x52, 0x0a, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x73, 0x2a,
-	0x48, 0x0a, 0x05, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x15, 0x0a, 0x11, 0x42, 0x4c, 0x53, 0x5f,
-	0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x42, 0x59, 0x54, 0x45, 0x53, 0x10, 0x00, 0x12,
-	0x1b, 0x0a, 0x17, 0x42, 0x4c, 0x53, 0x5f, 0x56, 0x45, 0x52, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54,
-	0x49, 0x4f, 0x4e, 0x5f, 0x46, 0x41, 0x49, 0x4c, 0x45, 0x44, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07,
-	0x55, 0x4e, 0x4b, 0x4e, 0x4f, 0x57, 0x4e, 0x10, 0x02, 0x32, 0x8f, 0x03, 0x0a, 0x06, 0x53, 0x69,
-	0x67, 0x6e, 0x65, 0x72, 0x12, 0x49, 0x0a, 0x0c, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65,
-	0x4b, 0x65, 0x79, 0x73, 0x12, 0x1b, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x47, 0x65,
-	0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4b, 0x65, 0x79, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
-	0x74, 0x1a, 0x1c, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72,
-	0x61, 0x74, 0x65, 0x4b, 0x65, 0x79, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
-	0x31, 0x0a, 0x04, 0x53, 0x69, 0x67, 0x6e, 0x12, 0x13, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72,
-	0x2e, 0x53, 0x69, 0x67, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x14, 0x2e, 0x73,
-	0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x53, 0x69, 0x67, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-	0x73, 0x65, 0x12, 0x37, 0x0a, 0x06, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x12, 0x15, 0x2e, 0x73,
-	0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x52, 0x65, 0x71, 0x75,
-	0x65, 0x73, 0x74, 0x1a, 0x16, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x56, 0x65, 0x72,
-	0x69, 0x66, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x40, 0x0a, 0x09, 0x43,
-	0x72, 0x65, 0x61, 0x74, 0x65, 0x41, 0x50, 0x4b, 0x12, 0x18, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65,
-	0x72, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x41, 0x50, 0x4b, 0x52, 0x65, 0x71, 0x75, 0x65,
-	0x73, 0x74, 0x1a, 0x19, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x43, 0x72, 0x65, 0x61,
-	0x74, 0x65, 0x41, 0x50, 0x4b, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x44, 0x0a,
-	0x0b, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x50, 0x4b, 0x12, 0x1a, 0x2e, 0x73,
-	0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x50,
-	0x4b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x19, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65,
-	0x72, 0x2e, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f,
-	0x6e, 0x73, 0x65, 0x12, 0x46, 0x0a, 0x0c, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65,
-	0x53, 0x69, 0x67, 0x12, 0x1b, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x41, 0x67, 0x67,
+	0x6f, 0x72, 0x42, 0x05, 0x0a, 0x03, 0x73, 0x69, 0x67, 0x22, 0x46, 0x0a, 0x14, 0x53, 0x69, 0x67,
+	0x6e, 0x57, 0x69, 0x74, 0x68, 0x4b, 0x65, 0x79, 0x49, 0x64, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x12, 0x14, 0x0a, 0x05, 0x4b, 0x65, 0x79, 0x49, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c,
+	0x52, 0x05, 0x4b, 0x65, 0x79, 0x49, 0x64, 0x12, 0x18, 0x0a, 0x07, 0x4d, 0x65, 0x73, 0x73, 0x61,
+	0x67, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67,
+	0x65, 0x22, 0x11, 0x0a, 0x0f, 0x4c, 0x69, 0x73, 0x74, 0x4b, 0x65, 0x79, 0x73, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x22, 0x32, 0x0a, 0x10, 0x4c, 0x69, 0x73, 0x74, 0x4b, 0x65, 0x79, 0x73,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1e, 0x0a, 0x0a, 0x50, 0x75, 0x62, 0x6c,
+	0x69, 0x63, 0x4b, 0x65, 0x79, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0c, 0x52, 0x0a, 0x50, 0x75,
+	0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x73, 0x22, 0x59, 0x0a, 0x0d, 0x56, 0x65, 0x72, 0x69,
+	0x66, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x41, 0x70, 0x6b,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x41, 0x70, 0x6b, 0x12, 0x1c, 0x0a, 0x09, 0x53,
+	0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09,
+	0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x4d, 0x65, 0x73,
+	0x73, 0x61, 0x67, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x4d, 0x65, 0x73, 0x73,
+	0x61, 0x67, 0x65, 0x22, 0x56, 0x0a, 0x0e, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x16, 0x0a, 0x05, 0x56, 0x61, 0x6c, 0x69, 0x64, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x08, 0x48, 0x00, 0x52, 0x05, 0x56, 0x61, 0x6c, 0x69, 0x64, 0x12, 0x25, 0x0a,
+	0x05, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0d, 0x2e, 0x73,
+	0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x45,
+	0x72, 0x72, 0x6f, 0x72, 0x42, 0x05, 0x0a, 0x03, 0x76, 0x65, 0x72, 0x22, 0x41, 0x0a, 0x12, 0x42,
+	0x61, 0x74, 0x63, 0x68, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x12, 0x2b, 0x0a, 0x05, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b,
+	0x32, 0x15, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x52, 0x05, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x22, 0x47,
+	0x0a, 0x13, 0x42, 0x61, 0x74, 0x63, 0x68, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x30, 0x0a, 0x07, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73,
+	0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x16, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e,
+	0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x52, 0x07,
+	0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x22, 0x46, 0x0a, 0x10, 0x42, 0x61, 0x74, 0x63, 0x68,
+	0x53, 0x69, 0x67, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x32, 0x0a, 0x05, 0x49,
+	0x74, 0x65, 0x6d, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x73, 0x69, 0x67,
+	0x6e, 0x65, 0x72, 0x2e, 0x53, 0x69, 0x67, 0x6e, 0x57, 0x69, 0x74, 0x68, 0x4b, 0x65, 0x79, 0x49,
+	0x64, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x52, 0x05, 0x49, 0x74, 0x65, 0x6d, 0x73, 0x22,
+	0x43, 0x0a, 0x11, 0x42, 0x61, 0x74, 0x63, 0x68, 0x53, 0x69, 0x67, 0x6e, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x2e, 0x0a, 0x07, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x18,
+	0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x53,
+	0x69, 0x67, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x52, 0x07, 0x52, 0x65, 0x73,
+	0x75, 0x6c, 0x74, 0x73, 0x22, 0x30, 0x0a, 0x10, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x41, 0x50,
+	0x4b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1c, 0x0a, 0x09, 0x50, 0x75, 0x62, 0x6c,
+	0x69, 0x63, 0x4b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x50, 0x75, 0x62,
+	0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x22, 0x55, 0x0a, 0x11, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65,
+	0x41, 0x50, 0x4b, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x03, 0x41,
+	0x50, 0x4b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x48, 0x00, 0x52, 0x03, 0x41, 0x50, 0x4b, 0x12,
+	0x25, 0x0a, 0x05, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0d,
+	0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52,
+	0x05, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x42, 0x05, 0x0a, 0x03, 0x61, 0x70, 0x6b, 0x22, 0x3a, 0x0a,
+	0x12, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x50, 0x4b, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x12, 0x10, 0x0a, 0x03, 0x41, 0x50, 0x4b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c,
+	0x52, 0x03, 0x41, 0x50, 0x4b, 0x12, 0x12, 0x0a, 0x04, 0x4b, 0x65, 0x79, 0x73, 0x18, 0x02, 0x20,
+	0x03, 0x28, 0x0c, 0x52, 0x04, 0x4b, 0x65, 0x79, 0x73, 0x22, 0x53, 0x0a, 0x13, 0x41, 0x67, 0x67,
 	0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x53, 0x69, 0x67, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-	0x1a, 0x19, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67,
-	0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x42, 0x3e, 0x5a, 0x3c, 0x67,
-	0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x64, 0x75, 0x73, 0x6b, 0x2d, 0x6e,
-	0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2f, 0x62, 0x6c, 0x73, 0x31, 0x32, 0x5f, 0x33, 0x38, 0x31,
-	0x2d, 0x73, 0x69, 0x67, 0x6e, 0x2f, 0x62, 0x6c, 0x73, 0x31, 0x32, 0x5f, 0x33, 0x38, 0x31, 0x2d,
-	0x73, 0x69, 0x67, 0x6e, 0x2d, 0x67, 0x6f, 0x2f, 0x62, 0x6c, 0x73, 0x62, 0x06, 0x70, 0x72, 0x6f,
-	0x74, 0x6f, 0x33,
+	0x12, 0x1c, 0x0a, 0x09, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0c, 0x52, 0x09, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x12, 0x1e,
+	0x0a, 0x0a, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03,
+	0x28, 0x0c, 0x52, 0x0a, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x73, 0x22, 0x57,
+	0x0a, 0x11, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0c, 0x48, 0x00, 0x52, 0x04, 0x43, 0x6f, 0x64, 0x65, 0x12, 0x25, 0x0a, 0x05, 0x45, 0x72, 0x72,
+	0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0d, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65,
+	0x72, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x05, 0x45, 0x72, 0x72, 0x6f, 0x72,
+	0x42, 0x05, 0x0a, 0x03, 0x61, 0x67, 0x67, 0x2a, 0xa6, 0x02, 0x0a, 0x05, 0x45, 0x72, 0x72, 0x6f,
+	0x72, 0x12, 0x15, 0x0a, 0x11, 0x42, 0x4c, 0x53, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44,
+	0x5f, 0x42, 0x59, 0x54, 0x45, 0x53, 0x10, 0x00, 0x12, 0x1b, 0x0a, 0x17, 0x42, 0x4c, 0x53, 0x5f,
+	0x56, 0x45, 0x52, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x46, 0x41, 0x49,
+	0x4c, 0x45, 0x44, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x55, 0x4e, 0x4b, 0x4e, 0x4f, 0x57, 0x4e,
+	0x10, 0x02, 0x12, 0x19, 0x0a, 0x15, 0x42, 0x4c, 0x53, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49,
+	0x44, 0x5f, 0x54, 0x48, 0x52, 0x45, 0x53, 0x48, 0x4f, 0x4c, 0x44, 0x10, 0x03, 0x12, 0x16, 0x0a,
+	0x12, 0x42, 0x4c, 0x53, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x53, 0x48, 0x41,
+	0x52, 0x45, 0x53, 0x10, 0x04, 0x12, 0x1a, 0x0a, 0x16, 0x42, 0x4c, 0x53, 0x5f, 0x49, 0x4e, 0x56,
+	0x41, 0x4c, 0x49, 0x44, 0x5f, 0x43, 0x49, 0x50, 0x48, 0x45, 0x52, 0x54, 0x45, 0x58, 0x54, 0x10,
+	0x05, 0x12, 0x15, 0x0a, 0x11, 0x42, 0x4c, 0x53, 0x5f, 0x4e, 0x4f, 0x54, 0x5f, 0x59, 0x45, 0x54,
+	0x5f, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x10, 0x06, 0x12, 0x0f, 0x0a, 0x0b, 0x42, 0x4c, 0x53, 0x5f,
+	0x45, 0x58, 0x50, 0x49, 0x52, 0x45, 0x44, 0x10, 0x07, 0x12, 0x0e, 0x0a, 0x0a, 0x42, 0x4c, 0x53,
+	0x5f, 0x52, 0x45, 0x50, 0x4c, 0x41, 0x59, 0x10, 0x08, 0x12, 0x13, 0x0a, 0x0f, 0x42, 0x4c, 0x53,
+	0x5f, 0x44, 0x4f, 0x55, 0x42, 0x4c, 0x45, 0x5f, 0x53, 0x49, 0x47, 0x4e, 0x10, 0x09, 0x12, 0x0f,
+	0x0a, 0x0b, 0x42, 0x4c, 0x53, 0x5f, 0x53, 0x54, 0x4f, 0x52, 0x41, 0x47, 0x45, 0x10, 0x0a, 0x12,
+	0x1b, 0x0a, 0x17, 0x42, 0x4c, 0x53, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x5f, 0x49,
+	0x4e, 0x54, 0x45, 0x52, 0x43, 0x48, 0x41, 0x4e, 0x47, 0x45, 0x10, 0x0b, 0x12, 0x12, 0x0a, 0x0e,
+	0x55, 0x4e, 0x4b, 0x4e, 0x4f, 0x57, 0x4e, 0x5f, 0x4b, 0x45, 0x59, 0x5f, 0x49, 0x44, 0x10, 0x0c,
+	0x32, 0xe0, 0x05, 0x0a, 0x06, 0x53, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x12, 0x49, 0x0a, 0x0c, 0x47,
+	0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4b, 0x65, 0x79, 0x73, 0x12, 0x1b, 0x2e, 0x73, 0x69,
+	0x67, 0x6e, 0x65, 0x72, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4b, 0x65, 0x79,
+	0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x1c, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65,
+	0x72, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4b, 0x65, 0x79, 0x73, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x31, 0x0a, 0x04, 0x53, 0x69, 0x67, 0x6e, 0x12, 0x13,
+	0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x53, 0x69, 0x67, 0x6e, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x1a, 0x14, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x53, 0x69, 0x67,
+	0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x37, 0x0a, 0x06, 0x56, 0x65, 0x72,
+	0x69, 0x66, 0x79, 0x12, 0x15, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x56, 0x65, 0x72,
+	0x69, 0x66, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x16, 0x2e, 0x73, 0x69, 0x67,
+	0x6e, 0x65, 0x72, 0x2e, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x40, 0x0a, 0x09, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x41, 0x50, 0x4b, 0x12,
+	0x18, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x41,
+	0x50, 0x4b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x19, 0x2e, 0x73, 0x69, 0x67, 0x6e,
+	0x65, 0x72, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x41, 0x50, 0x4b, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x44, 0x0a, 0x0b, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74,
+	0x65, 0x50, 0x4b, 0x12, 0x1a, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x41, 0x67, 0x67,
+	0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x50, 0x4b, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+	0x19, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61,
+	0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x46, 0x0a, 0x0c, 0x41, 0x67,
+	0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x53, 0x69, 0x67, 0x12, 0x1b, 0x2e, 0x73, 0x69, 0x67,
+	0x6e, 0x65, 0x72, 0x2e, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x53, 0x69, 0x67,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x19, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72,
+	0x2e, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x43, 0x0a, 0x0d, 0x53, 0x69, 0x67, 0x6e, 0x57, 0x69, 0x74, 0x68, 0x4b, 0x65,
+	0x79, 0x49, 0x64, 0x12, 0x1c, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x53, 0x69, 0x67,
+	0x6e, 0x57, 0x69, 0x74, 0x68, 0x4b, 0x65, 0x79, 0x49, 0x64, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x14, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x53, 0x69, 0x67, 0x6e, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3d, 0x0a, 0x08, 0x4c, 0x69, 0x73, 0x74, 0x4b,
+	0x65, 0x79, 0x73, 0x12, 0x17, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x4c, 0x69, 0x73,
+	0x74, 0x4b, 0x65, 0x79, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x18, 0x2e, 0x73,
+	0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x4c, 0x69, 0x73, 0x74, 0x4b, 0x65, 0x79, 0x73, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x46, 0x0a, 0x0b, 0x42, 0x61, 0x74, 0x63, 0x68, 0x56,
+	0x65, 0x72, 0x69, 0x66, 0x79, 0x12, 0x1a, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x42,
+	0x61, 0x74, 0x63, 0x68, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x1b, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68,
+	0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x40,
+	0x0a, 0x09, 0x42, 0x61, 0x74, 0x63, 0x68, 0x53, 0x69, 0x67, 0x6e, 0x12, 0x18, 0x2e, 0x73, 0x69,
+	0x67, 0x6e, 0x65, 0x72, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x53, 0x69, 0x67, 0x6e, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x19, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x42,
+	0x61, 0x74, 0x63, 0x68, 0x53, 0x69, 0x67, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x41, 0x0a, 0x0c, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x53, 0x74, 0x72, 0x65, 0x61, 0x6d,
+	0x12, 0x15, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72, 0x2e, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x16, 0x2e, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x72,
+	0x2e, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x28,
+	0x01, 0x30, 0x01, 0x42, 0x2f, 0x5a, 0x2d, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f,
+	0x6d, 0x2f, 0x64, 0x75, 0x73, 0x6b, 0x2d, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2f, 0x62,
+	0x6c, 0x73, 0x31, 0x32, 0x5f, 0x33, 0x38, 0x31, 0x2d, 0x73, 0x69, 0x67, 0x6e, 0x2f, 0x67, 0x6f,
+	0x2f, 0x62, 0x6c, 0x73, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 }
 
 var (
@@ -883,43 +1325,64 @@ func file_bls12381sig_proto_rawDescGZIP() []byte {
 }
 
 var file_bls12381sig_proto_enumTypes = make([]protoimpl.EnumInfo, 1)
-var file_bls12381sig_proto_msgTypes = make([]protoimpl.MessageInfo, 11)
+var file_bls12381sig_proto_msgTypes = make([]protoimpl.MessageInfo, 18)
 var file_bls12381sig_proto_goTypes = []interface{}{
 	(Error)(0),                   // 0: signer.Error
 	(*GenerateKeysRequest)(nil),  // 1: signer.GenerateKeysRequest
 	(*GenerateKeysResponse)(nil), // 2: signer.GenerateKeysResponse
 	(*SignRequest)(nil),          // 3: signer.SignRequest
 	(*SignResponse)(nil),         // 4: signer.SignResponse
-	(*VerifyRequest)(nil),        // 5: signer.VerifyRequest
-	(*VerifyResponse)(nil),       // 6: signer.VerifyResponse
-	(*CreateAPKRequest)(nil),     // 7: signer.CreateAPKRequest
-	(*CreateAPKResponse)(nil),    // 8: signer.CreateAPKResponse
-	(*AggregatePKRequest)(nil),   // 9: signer.AggregatePKRequest
-	(*AggregateResponse)(nil),    // 10: signer.AggregateResponse
-	(*AggregateSigRequest)(nil),  // 11: signer.AggregateSigRequest
+	(*SignWithKeyIdRequest)(nil), // 5: signer.SignWithKeyIdRequest
+	(*ListKeysRequest)(nil),      // 6: signer.ListKeysRequest
+	(*ListKeysResponse)(nil),     // 7: signer.ListKeysResponse
+	(*VerifyRequest)(nil),        // 8: signer.VerifyRequest
+	(*VerifyResponse)(nil),       // 9: signer.VerifyResponse
+	(*BatchVerifyRequest)(nil),   // 10: signer.BatchVerifyRequest
+	(*BatchVerifyResponse)(nil),  // 11: signer.BatchVerifyResponse
+	(*BatchSignRequest)(nil),     // 12: signer.BatchSignRequest
+	(*BatchSignResponse)(nil),    // 13: signer.BatchSignResponse
+	(*CreateAPKRequest)(nil),     // 14: signer.CreateAPKRequest
+	(*CreateAPKResponse)(nil),    // 15: signer.CreateAPKResponse
+	(*AggregatePKRequest)(nil),   // 16: signer.AggregatePKRequest
+	(*AggregateSigRequest)(nil),  // 17: signer.AggregateSigRequest
+	(*AggregateResponse)(nil),    // 18: signer.AggregateResponse
 }
 var file_bls12381sig_proto_depIdxs = []int32{
 	0,  // 0: signer.SignResponse.Error:type_name -> signer.Error
 	0,  // 1: signer.VerifyResponse.Error:type_name -> signer.Error
-	0,  // 2: signer.CreateAPKResponse.Error:type_name -> signer.Error
-	0,  // 3: signer.AggregateResponse.Error:type_name -> signer.Error
-	1,  // 4: signer.Signer.GenerateKeys:input_type -> signer.GenerateKeysRequest
-	3,  // 5: signer.Signer.Sign:input_type -> signer.SignRequest
-	5,  // 6: signer.Signer.Verify:input_type -> signer.VerifyRequest
-	7,  // 7: signer.Signer.CreateAPK:input_type -> signer.CreateAPKRequest
-	9,  // 8: signer.Signer.AggregatePK:input_type -> signer.AggregatePKRequest
-	11, // 9: signer.Signer.AggregateSig:input_type -> signer.AggregateSigRequest
-	2,  // 10: signer.Signer.GenerateKeys:output_type -> signer.GenerateKeysResponse
-	4,  // 11: signer.Signer.Sign:output_type -> signer.SignResponse
-	6,  // 12: signer.Signer.Verify:output_type -> signer.VerifyResponse
-	8,  // 13: signer.Signer.CreateAPK:output_type -> signer.CreateAPKResponse
-	10, // 14: signer.Signer.AggregatePK:output_type -> signer.AggregateResponse
-	10, // 15: signer.Signer.AggregateSig:output_type -> signer.AggregateResponse
-	10, // [10:16] is the sub-list for method output_type
-	4,  // [4:10] is the sub-list for method input_type
-	4,  // [4:4] is the sub-list for extension type_name
-	4,  // [4:4] is the sub-list for extension extendee
-	0,  // [0:4] is the sub-list for field type_name
+	8,  // 2: signer.BatchVerifyRequest.Items:type_name -> signer.VerifyRequest
+	9,  // 3: signer.BatchVerifyResponse.Results:type_name -> signer.VerifyResponse
+	5,  // 4: signer.BatchSignRequest.Items:type_name -> signer.SignWithKeyIdRequest
+	4,  // 5: signer.BatchSignResponse.Results:type_name -> signer.SignResponse
+	0,  // 6: signer.CreateAPKResponse.Error:type_name -> signer.Error
+	0,  // 7: signer.AggregateResponse.Error:type_name -> signer.Error
+	1,  // 8: signer.Signer.GenerateKeys:input_type -> signer.GenerateKeysRequest
+	3,  // 9: signer.Signer.Sign:input_type -> signer.SignRequest
+	8,  // 10: signer.Signer.Verify:input_type -> signer.VerifyRequest
+	14, // 11: signer.Signer.CreateAPK:input_type -> signer.CreateAPKRequest
+	16, // 12: signer.Signer.AggregatePK:input_type -> signer.AggregatePKRequest
+	17, // 13: signer.Signer.AggregateSig:input_type -> signer.AggregateSigRequest
+	5,  // 14: signer.Signer.SignWithKeyId:input_type -> signer.SignWithKeyIdRequest
+	6,  // 15: signer.Signer.ListKeys:input_type -> signer.ListKeysRequest
+	10, // 16: signer.Signer.BatchVerify:input_type -> signer.BatchVerifyRequest
+	12, // 17: signer.Signer.BatchSign:input_type -> signer.BatchSignRequest
+	8,  // 18: signer.Signer.VerifyStream:input_type -> signer.VerifyRequest
+	2,  // 19: signer.Signer.GenerateKeys:output_type -> signer.GenerateKeysResponse
+	4,  // 20: signer.Signer.Sign:output_type -> signer.SignResponse
+	9,  // 21: signer.Signer.Verify:output_type -> signer.VerifyResponse
+	15, // 22: signer.Signer.CreateAPK:output_type -> signer.CreateAPKResponse
+	18, // 23: signer.Signer.AggregatePK:output_type -> signer.AggregateResponse
+	18, // 24: signer.Signer.AggregateSig:output_type -> signer.AggregateResponse
+	4,  // 25: signer.Signer.SignWithKeyId:output_type -> signer.SignResponse
+	7,  // 26: signer.Signer.ListKeys:output_type -> signer.ListKeysResponse
+	11, // 27: signer.Signer.BatchVerify:output_type -> signer.BatchVerifyResponse
+	13, // 28: signer.Signer.BatchSign:output_type -> signer.BatchSignResponse
+	9,  // 29: signer.Signer.VerifyStream:output_type -> signer.VerifyResponse
+	19, // [19:30] is the sub-list for method output_type
+	8,  // [8:19] is the sub-list for method input_type
+	8,  // [8:8] is the sub-list for extension type_name
+	8,  // [8:8] is the sub-list for extension extendee
+	0,  // [0:8] is the sub-list for field type_name
 }
 
 func init() { file_bls12381sig_proto_init() }
@@ -977,7 +1440,7 @@ func file_bls12381sig_proto_init() {
 			}
 		}
 		file_bls12381sig_proto_msgTypes[4].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*VerifyRequest); i {
+			switch v := v.(*SignWithKeyIdRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -989,7 +1452,7 @@ func file_bls12381sig_proto_init() {
 			}
 		}
 		file_bls12381sig_proto_msgTypes[5].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*VerifyResponse); i {
+			switch v := v.(*ListKeysRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1001,7 +1464,7 @@ func file_bls12381sig_proto_init() {
 			}
 		}
 		file_bls12381sig_proto_msgTypes[6].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CreateAPKRequest); i {
+			switch v := v.(*ListKeysResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1013,7 +1476,7 @@ func file_bls12381sig_proto_init() {
 			}
 		}
 		file_bls12381sig_proto_msgTypes[7].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CreateAPKResponse); i {
+			switch v := v.(*VerifyRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1025,7 +1488,7 @@ func file_bls12381sig_proto_init() {
 			}
 		}
 		file_bls12381sig_proto_msgTypes[8].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AggregatePKRequest); i {
+			switch v := v.(*VerifyResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1037,7 +1500,7 @@ func file_bls12381sig_proto_init() {
 			}
 		}
 		file_bls12381sig_proto_msgTypes[9].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AggregateResponse); i {
+			switch v := v.(*BatchVerifyRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -1049,6 +1512,78 @@ func file_bls12381sig_proto_init() {
 			}
 		}
 		file_bls12381sig_proto_msgTypes[10].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchVerifyResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_bls12381sig_proto_msgTypes[11].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchSignRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_bls12381sig_proto_msgTypes[12].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchSignResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_bls12381sig_proto_msgTypes[13].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateAPKRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_bls12381sig_proto_msgTypes[14].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateAPKResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_bls12381sig_proto_msgTypes[15].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AggregatePKRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_bls12381sig_proto_msgTypes[16].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*AggregateSigRequest); i {
 			case 0:
 				return &v.state
@@ -1060,20 +1595,32 @@ func file_bls12381sig_proto_init() {
 				return nil
 			}
 		}
+		file_bls12381sig_proto_msgTypes[17].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AggregateResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
 	}
 	file_bls12381sig_proto_msgTypes[3].OneofWrappers = []interface{}{
 		(*SignResponse_Signature)(nil),
 		(*SignResponse_Error)(nil),
 	}
-	file_bls12381sig_proto_msgTypes[5].OneofWrappers = []interface{}{
+	file_bls12381sig_proto_msgTypes[8].OneofWrappers = []interface{}{
 		(*VerifyResponse_Valid)(nil),
 		(*VerifyResponse_Error)(nil),
 	}
-	file_bls12381sig_proto_msgTypes[7].OneofWrappers = []interface{}{
+	file_bls12381sig_proto_msgTypes[14].OneofWrappers = []interface{}{
 		(*CreateAPKResponse_APK)(nil),
 		(*CreateAPKResponse_Error)(nil),
 	}
-	file_bls12381sig_proto_msgTypes[9].OneofWrappers = []interface{}{
+	file_bls12381sig_proto_msgTypes[17].OneofWrappers = []interface{}{
 		(*AggregateResponse_Code)(nil),
 		(*AggregateResponse_Error)(nil),
 	}
@@ -1083,7 +1630,7 @@ func file_bls12381sig_proto_init() {
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: file_bls12381sig_proto_rawDesc,
 			NumEnums:      1,
-			NumMessages:   11,
+			NumMessages:   18,
 			NumExtensions: 0,
 			NumServices:   1,
 		},
@@ -1116,6 +1663,11 @@ type SignerClient interface {
 	CreateAPK(ctx context.Context, in *CreateAPKRequest, opts ...grpc.CallOption) (*CreateAPKResponse, error)
 	AggregatePK(ctx context.Context, in *AggregatePKRequest, opts ...grpc.CallOption) (*AggregateResponse, error)
 	AggregateSig(ctx context.Context, in *AggregateSigRequest, opts ...grpc.CallOption) (*AggregateResponse, error)
+	SignWithKeyId(ctx context.Context, in *SignWithKeyIdRequest, opts ...grpc.CallOption) (*SignResponse, error)
+	ListKeys(ctx context.Context, in *ListKeysRequest, opts ...grpc.CallOption) (*ListKeysResponse, error)
+	BatchVerify(ctx context.Context, in *BatchVerifyRequest, opts ...grpc.CallOption) (*BatchVerifyResponse, error)
+	BatchSign(ctx context.Context, in *BatchSignRequest, opts ...grpc.CallOption) (*BatchSignResponse, error)
+	VerifyStream(ctx context.Context, opts ...grpc.CallOption) (Signer_VerifyStreamClient, error)
 }
 
 type signerClient struct {
@@ -1180,6 +1732,73 @@ func (c *signerClient) AggregateSig(ctx context.Context, in *AggregateSigRequest
 	return out, nil
 }
 
+func (c *signerClient) SignWithKeyId(ctx context.Context, in *SignWithKeyIdRequest, opts ...grpc.CallOption) (*SignResponse, error) {
+	out := new(SignResponse)
+	err := c.cc.Invoke(ctx, "/signer.Signer/SignWithKeyId", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *signerClient) ListKeys(ctx context.Context, in *ListKeysRequest, opts ...grpc.CallOption) (*ListKeysResponse, error) {
+	out := new(ListKeysResponse)
+	err := c.cc.Invoke(ctx, "/signer.Signer/ListKeys", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *signerClient) BatchVerify(ctx context.Context, in *BatchVerifyRequest, opts ...grpc.CallOption) (*BatchVerifyResponse, error) {
+	out := new(BatchVerifyResponse)
+	err := c.cc.Invoke(ctx, "/signer.Signer/BatchVerify", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *signerClient) BatchSign(ctx context.Context, in *BatchSignRequest, opts ...grpc.CallOption) (*BatchSignResponse, error) {
+	out := new(BatchSignResponse)
+	err := c.cc.Invoke(ctx, "/signer.Signer/BatchSign", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *signerClient) VerifyStream(ctx context.Context, opts ...grpc.CallOption) (Signer_VerifyStreamClient, error) {
+	stream, err := c.cc.NewStream(ctx, &_Signer_serviceDesc.Streams[0], "/signer.Signer/VerifyStream", opts...)
+	if err != nil {
+		return nil, err
+	}
+	x := &signerVerifyStreamClient{stream}
+	return x, nil
+}
+
+type Signer_VerifyStreamClient interface {
+	Send(*VerifyRequest) error
+	Recv() (*VerifyResponse, error)
+	grpc.ClientStream
+}
+
+type signerVerifyStreamClient struct {
+	grpc.ClientStream
+}
+
+func (x *signerVerifyStreamClient) Send(m *VerifyRequest) error {
+	return x.ClientStream.SendMsg(m)
+}
+
+func (x *signerVerifyStreamClient) Recv() (*VerifyResponse, error) {
+	m := new(VerifyResponse)
+	if err := x.ClientStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
 // SignerServer is the server API for Signer service.
 type SignerServer interface {
 	GenerateKeys(context.Context, *GenerateKeysRequest) (*GenerateKeysResponse, error)
@@ -1188,6 +1807,11 @@ type SignerServer interface {
 	CreateAPK(context.Context, *CreateAPKRequest) (*CreateAPKResponse, error)
 	AggregatePK(context.Context, *AggregatePKRequest) (*AggregateResponse, error)
 	AggregateSig(context.Context, *AggregateSigRequest) (*AggregateResponse, error)
+	SignWithKeyId(context.Context, *SignWithKeyIdRequest) (*SignResponse, error)
+	ListKeys(context.Context, *ListKeysRequest) (*ListKeysResponse, error)
+	BatchVerify(context.Context, *BatchVerifyRequest) (*BatchVerifyResponse, error)
+	BatchSign(context.Context, *BatchSignRequest) (*BatchSignResponse, error)
+	VerifyStream(Signer_VerifyStreamServer) error
 }
 
 // UnimplementedSignerServer can be embedded to have forward compatible implementations.
@@ -1212,6 +1836,21 @@ func (*UnimplementedSignerServer) AggregatePK(context.Context, *AggregatePKReque
 func (*UnimplementedSignerServer) AggregateSig(context.Context, *AggregateSigRequest) (*AggregateResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method AggregateSig not implemented")
 }
+func (*UnimplementedSignerServer) SignWithKeyId(context.Context, *SignWithKeyIdRequest) (*SignResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method SignWithKeyId not implemented")
+}
+func (*UnimplementedSignerServer) ListKeys(context.Context, *ListKeysRequest) (*ListKeysResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ListKeys not implemented")
+}
+func (*UnimplementedSignerServer) BatchVerify(context.Context, *BatchVerifyRequest) (*BatchVerifyResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method BatchVerify not implemented")
+}
+func (*UnimplementedSignerServer) BatchSign(context.Context, *BatchSignRequest) (*BatchSignResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method BatchSign not implemented")
+}
+func (*UnimplementedSignerServer) VerifyStream(Signer_VerifyStreamServer) error {
+	return status.Errorf(codes.Unimplemented, "method VerifyStream not implemented")
+}
 
 func RegisterSignerServer(s *grpc.Server, srv SignerServer) {
 	s.RegisterService(&_Signer_serviceDesc, srv)
@@ -1325,6 +1964,104 @@ func _Signer_AggregateSig_Handler(srv interface{}, ctx context.Context, dec func
 	return interceptor(ctx, in, info, handler)
 }
 
+func _Signer_SignWithKeyId_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SignWithKeyIdRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SignerServer).SignWithKeyId(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/signer.Signer/SignWithKeyId",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SignerServer).SignWithKeyId(ctx, req.(*SignWithKeyIdRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Signer_ListKeys_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ListKeysRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SignerServer).ListKeys(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/signer.Signer/ListKeys",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SignerServer).ListKeys(ctx, req.(*ListKeysRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Signer_BatchVerify_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(BatchVerifyRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SignerServer).BatchVerify(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/signer.Signer/BatchVerify",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SignerServer).BatchVerify(ctx, req.(*BatchVerifyRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Signer_BatchSign_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(BatchSignRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(SignerServer).BatchSign(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/signer.Signer/BatchSign",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(SignerServer).BatchSign(ctx, req.(*BatchSignRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _Signer_VerifyStream_Handler(srv interface{}, stream grpc.ServerStream) error {
+	return srv.(SignerServer).VerifyStream(&signerVerifyStreamServer{stream})
+}
+
+type Signer_VerifyStreamServer interface {
+	Send(*VerifyResponse) error
+	Recv() (*VerifyRequest, error)
+	grpc.ServerStream
+}
+
+type signerVerifyStreamServer struct {
+	grpc.ServerStream
+}
+
+func (x *signerVerifyStreamServer) Send(m *VerifyResponse) error {
+	return x.ServerStream.SendMsg(m)
+}
+
+func (x *signerVerifyStreamServer) Recv() (*VerifyRequest, error) {
+	m := new(VerifyRequest)
+	if err := x.ServerStream.RecvMsg(m); err != nil {
+		return nil, err
+	}
+	return m, nil
+}
+
 var _Signer_serviceDesc = grpc.ServiceDesc{
 	ServiceName: "signer.Signer",
 	HandlerType: (*SignerServer)(nil),
@@ -1353,7 +2090,30 @@ var _Signer_serviceDesc = grpc.ServiceDesc{
 			MethodName: "AggregateSig",
 			Handler:    _Signer_AggregateSig_Handler,
 		},
+		{
+			MethodName: "SignWithKeyId",
+			Handler:    _Signer_SignWithKeyId_Handler,
+		},
+		{
+			MethodName: "ListKeys",
+			Handler:    _Signer_ListKeys_Handler,
+		},
+		{
+			MethodName: "BatchVerify",
+			Handler:    _Signer_BatchVerify_Handler,
+		},
+		{
+			MethodName: "BatchSign",
+			Handler:    _Signer_BatchSign_Handler,
+		},
+	},
+	Streams: []grpc.StreamDesc{
+		{
+			StreamName:    "VerifyStream",
+			Handler:       _Signer_VerifyStream_Handler,
+			ServerStreams: true,
+			ClientStreams: true,
+		},
 	},
-	Streams:  []grpc.StreamDesc{},
 	Metadata: "bls12381sig.proto",
 }
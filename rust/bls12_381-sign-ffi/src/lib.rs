@@ -19,6 +19,7 @@ pub const PK_RAW_SIZE: usize = G2Affine::RAW_SIZE;
 pub const RES_OK: c_int = 0;
 pub const RES_BYTES_ERROR: c_int = 1;
 pub const RES_INVALID_SIGNATURE: c_int = 2;
+pub const RES_UNKNOWN_ERROR: c_int = 3;
 
 macro_rules! unwrap_or_bail {
     ( $e: expr ) => {
@@ -26,6 +27,7 @@ macro_rules! unwrap_or_bail {
             Ok(v) => v,
             Err(Error::BytesError(_)) => return RES_BYTES_ERROR,
             Err(Error::InvalidSignature) => return RES_INVALID_SIGNATURE,
+            Err(_) => return RES_UNKNOWN_ERROR,
         }
     };
 }
@@ -0,0 +1,168 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Batched operations over sets of signatures.
+
+use crate::hash::h0;
+use crate::{Error, PublicKey, Signature, APK};
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::{
+    multi_miller_loop, BlsScalar, G1Affine, G1Projective, G2Affine, G2Prepared,
+    G2Projective, Gt,
+};
+use ff::Field;
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Identify the signers whose [`Signature`] is invalid for `msg`.
+///
+/// Every entry pairs the [`PublicKey`] of a signer with the [`Signature`]
+/// they contributed to an aggregate, as produced by `SecretKey::sign`.
+/// Returns the indexes of the invalid entries in ascending order, or an empty
+/// vector if all of them are valid.
+///
+/// The entries are checked by recursively bisecting the set and verifying
+/// the aggregate of each half, so `k` invalid signers out of `n` cost in the
+/// order of `k * log(n)` pairing checks instead of `n`. Every entry is
+/// weighted by a random scalar drawn from `rng` beforehand, preventing
+/// invalid signatures from cancelling each other out within a half.
+pub fn find_invalid_signers<R>(
+    rng: &mut R,
+    msg: &[u8],
+    signers: &[(PublicKey, Signature)],
+) -> Vec<usize>
+where
+    R: RngCore + CryptoRng,
+{
+    let mut invalid = Vec::new();
+    if signers.is_empty() {
+        return invalid;
+    }
+
+    let weights: Vec<BlsScalar> = signers
+        .iter()
+        .map(|_| BlsScalar::random(&mut *rng))
+        .collect();
+
+    #[cfg(feature = "parallel")]
+    let iter = signers.par_iter().zip(weights.par_iter());
+
+    #[cfg(not(feature = "parallel"))]
+    let iter = signers.iter().zip(weights.iter());
+
+    let weighted: Vec<(G2Projective, G1Projective)> = iter
+        .map(|((pk, sig), r)| (pk.pk_t() * r, sig.0 * r))
+        .collect();
+
+    let batch = Batch {
+        h0m: -h0(msg),
+        g2: G2Prepared::from(G2Affine::generator()),
+        weighted,
+    };
+
+    if batch.is_valid(0, signers.len()) {
+        return invalid;
+    }
+
+    // Every range on the stack is known to contain at least one invalid
+    // signature.
+    let mut stack = Vec::from([(0, signers.len())]);
+    while let Some((lo, hi)) = stack.pop() {
+        if hi - lo == 1 {
+            invalid.push(lo);
+            continue;
+        }
+
+        let mid = lo + (hi - lo) / 2;
+        if batch.is_valid(lo, mid) {
+            // If the left half is valid, the right one can't be.
+            stack.push((mid, hi));
+        } else {
+            if !batch.is_valid(mid, hi) {
+                stack.push((mid, hi));
+            }
+            stack.push((lo, mid));
+        }
+    }
+
+    invalid.sort_unstable();
+    invalid
+}
+
+/// Verify a batch of [`Signature`]s, each over its own message and by its own
+/// [`APK`], as `APK::verify` would for every entry.
+///
+/// The whole batch is checked with a single final exponentiation, weighting
+/// every entry by a random scalar drawn from `rng` so that invalid signatures
+/// can't cancel each other out. Fails with [`Error::InvalidSignature`] if any
+/// of the entries is invalid, without telling which.
+pub fn verify_batch<R>(
+    rng: &mut R,
+    batch: &[(APK, Signature, &[u8])],
+) -> Result<(), Error>
+where
+    R: RngCore + CryptoRng,
+{
+    let weights: Vec<BlsScalar> =
+        batch.iter().map(|_| BlsScalar::random(&mut *rng)).collect();
+
+    #[cfg(feature = "parallel")]
+    let iter = batch.par_iter().zip(weights.par_iter());
+
+    #[cfg(not(feature = "parallel"))]
+    let iter = batch.iter().zip(weights.iter());
+
+    // e(Σ r_i * sig_i, g_2) == Π e(r_i * Hₒ(m_i), apk_i)
+    let terms: Vec<(G1Projective, G1Affine, G2Prepared)> = iter
+        .map(|((apk, sig, msg), r)| {
+            let h0m = G1Affine::from(-(h0(msg) * r));
+            (sig.0 * r, h0m, G2Prepared::from((apk.0).0))
+        })
+        .collect();
+
+    let sig: G1Projective = terms.iter().map(|(sig, _, _)| sig).sum();
+    let sig = G1Affine::from(sig);
+    let g2 = G2Prepared::from(G2Affine::generator());
+
+    let mut pairs = Vec::with_capacity(terms.len() + 1);
+    pairs.push((&sig, &g2));
+    pairs.extend(terms.iter().map(|(_, h0m, apk)| (h0m, apk)));
+
+    if multi_miller_loop(&pairs).final_exponentiation() == Gt::identity() {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
+
+/// Weighted signers sharing the same message.
+struct Batch {
+    h0m: G1Affine,
+    g2: G2Prepared,
+    weighted: Vec<(G2Projective, G1Projective)>,
+}
+
+impl Batch {
+    /// Check e(sig, g_2) == e(Hₒ(m), apk) for the aggregate of the signers in
+    /// the range `lo..hi`, using a single final exponentiation.
+    fn is_valid(&self, lo: usize, hi: usize) -> bool {
+        let (apk, sig) = self.weighted[lo..hi].iter().fold(
+            (G2Projective::identity(), G1Projective::identity()),
+            |(apk, sig), (pk, s)| (apk + pk, sig + s),
+        );
+
+        let sig = G1Affine::from(sig);
+        let apk = G2Prepared::from(G2Affine::from(apk));
+
+        multi_miller_loop(&[(&sig, &self.g2), (&self.h0m, &apk)])
+            .final_exponentiation()
+            == Gt::identity()
+    }
+}
@@ -0,0 +1,154 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Randomness beacon driven by the threshold signatures of a committee.
+//!
+//! For every round the committee members sign the round message with their
+//! [`KeyShare`]s, and any `threshold` of the [`PartialSignature`]s combine
+//! into the round [`Signature`], whose hash is the random value of the round.
+//!
+//! Since BLS signatures are unique, the round signature doesn't depend on the
+//! members taking part. The round messages are hashed to the curve as the
+//! identities of the identity based encryption, with `h0_htc`, so the
+//! signatures of past rounds don't give away the signatures of the next ones,
+//! which can't be predicted without `threshold` of the shares. This also makes
+//! the round signature the decryption key of the messages encrypted to the
+//! round.
+
+use crate::groups::{self, MinSig};
+use crate::ibe::hash_identity;
+use crate::{Error, KeyShare, PartialSignature, PublicKey, Signature};
+
+use alloc::vec::Vec;
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use dusk_bytes::Serializable;
+
+/// Domain separation tag of the round messages.
+const BEACON_DST: &[u8] = b"DUSK_BLS12381_BEACON";
+
+/// How the message signed in each round is derived.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BeaconMode {
+    /// The message of a round includes the signature of the previous round,
+    /// so rounds can only be signed in sequence.
+    Chained,
+    /// The message of a round only includes the round number, so any round
+    /// can be signed, and encrypted to, in advance.
+    Unchained,
+}
+
+/// A randomness beacon run by a committee sharing the [`SecretKey`] of the
+/// group [`PublicKey`].
+///
+/// [`SecretKey`]: crate::SecretKey
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Beacon {
+    pk: PublicKey,
+    mode: BeaconMode,
+}
+
+impl Beacon {
+    /// Create a beacon verified with the group [`PublicKey`].
+    pub fn new(pk: PublicKey, mode: BeaconMode) -> Self {
+        Self { pk, mode }
+    }
+
+    /// The group [`PublicKey`] of the beacon.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.pk
+    }
+
+    /// The [`BeaconMode`] of the beacon.
+    pub fn mode(&self) -> BeaconMode {
+        self.mode
+    }
+
+    /// The message signed for `round`.
+    ///
+    /// In [`BeaconMode::Chained`] mode it includes the `previous` round
+    /// signature, with `None` standing for the genesis of the chain. In
+    /// [`BeaconMode::Unchained`] mode `previous` is ignored.
+    pub fn message(&self, round: u64, previous: Option<&Signature>) -> Vec<u8> {
+        let mut msg =
+            Vec::with_capacity(BEACON_DST.len() + 8 + Signature::SIZE);
+
+        msg.extend_from_slice(BEACON_DST);
+        msg.extend_from_slice(&round.to_be_bytes());
+        if self.mode == BeaconMode::Chained {
+            let previous = previous.copied().unwrap_or_default();
+            msg.extend_from_slice(&previous.to_bytes());
+        }
+
+        msg
+    }
+
+    /// Sign `round` with the [`KeyShare`] of a committee member.
+    pub fn sign_partial(
+        &self,
+        share: &KeyShare,
+        round: u64,
+        previous: Option<&Signature>,
+    ) -> PartialSignature {
+        share
+            .sign_partial_hashed(&hash_identity(&self.message(round, previous)))
+    }
+
+    /// Verify the [`PartialSignature`] of `round` by a committee member,
+    /// against the [`PublicKey`] of its [`KeyShare`].
+    pub fn verify_partial(
+        &self,
+        share_pk: &PublicKey,
+        round: u64,
+        previous: Option<&Signature>,
+        partial: &PartialSignature,
+    ) -> Result<(), Error> {
+        partial.verify_hashed(
+            share_pk,
+            &hash_identity(&self.message(round, previous)),
+        )
+    }
+
+    /// Combine the [`PartialSignature`]s of the committee members into the
+    /// [`Signature`] of `round`, and verify it.
+    pub fn combine(
+        &self,
+        round: u64,
+        previous: Option<&Signature>,
+        partials: &[PartialSignature],
+    ) -> Result<Signature, Error> {
+        let sig = PartialSignature::combine(partials)?;
+        self.verify(round, previous, &sig)?;
+
+        Ok(sig)
+    }
+
+    /// Verify the [`Signature`] of `round`, returning its random value.
+    ///
+    /// The group public key must be valid, since the identity would accept
+    /// the identity as signature of any round.
+    pub fn verify(
+        &self,
+        round: u64,
+        previous: Option<&Signature>,
+        sig: &Signature,
+    ) -> Result<[u8; 32], Error> {
+        if !self.pk.is_valid() {
+            return Err(Error::InvalidSignature);
+        }
+
+        let h = hash_identity(&self.message(round, previous));
+        groups::verify_hashed::<MinSig>(&self.pk.0, &sig.0, &h)?;
+
+        Ok(Self::randomness(sig))
+    }
+
+    /// The random value derived from a round [`Signature`].
+    pub fn randomness(sig: &Signature) -> [u8; 32] {
+        Blake2b::<U32>::digest(sig.to_bytes()).into()
+    }
+}
@@ -0,0 +1,172 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Blind signatures, letting a signer sign a message without learning it.
+//!
+//! The client blinds the hash of m with a random scalar r into a
+//! [`BlindedMessage`], the signer signs it into a [`BlindSignature`], and the
+//! client unblinds it with r⁻¹ into the [`Signature`] over m, verifiable with
+//! `PublicKey::verify_unblinded` and unlinkable to the blinded message.
+//!
+//! Messages are hashed to the curve with `h0_htc` under their own domain
+//! separation tag, since the discrete logarithm of the points of `h0` is
+//! public and one signature over them would give away all the others. The
+//! signer signs whatever point it is sent, so a key signing blinded messages
+//! must not be used for any other scheme.
+
+use crate::groups::{self, MinSig};
+use crate::hash::h0_htc;
+use crate::{Error, PublicKey, SecretKey, Signature};
+
+use dusk_bls12_381::{BlsScalar, G1Affine};
+use dusk_bytes::{Error as DuskBytesError, Serializable};
+use ff::Field;
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "rkyv-impl")]
+use rkyv::{Archive, Deserialize, Serialize};
+
+/// Domain separation tag for hashing the messages to be blinded.
+const BLIND_DST: &[u8] = b"DUSK_BLS12381G1_XMD:SHA-256_SSWU_RO_BLIND_";
+
+/// The random scalar a message is blinded with, to be kept by the client
+/// until the [`BlindSignature`] is unblinded.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv-impl",
+    derive(Archive, Deserialize, Serialize),
+    archive_attr(derive(bytecheck::CheckBytes))
+)]
+pub struct BlindingFactor(BlsScalar);
+
+impl Serializable<32> for BlindingFactor {
+    type Error = DuskBytesError;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        let factor: Option<BlsScalar> = BlsScalar::from_bytes(bytes).into();
+        match factor {
+            Some(factor) if factor != BlsScalar::zero() => Ok(Self(factor)),
+            _ => Err(DuskBytesError::InvalidData),
+        }
+    }
+}
+
+/// A message hashed to G1 and blinded, to be sent to the signer.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv-impl",
+    derive(Archive, Deserialize, Serialize),
+    archive_attr(derive(bytecheck::CheckBytes))
+)]
+pub struct BlindedMessage(G1Affine);
+
+impl BlindedMessage {
+    /// Blind a message, returning the [`BlindedMessage`] along with the
+    /// [`BlindingFactor`] needed to unblind its signature.
+    pub fn new<R>(rng: &mut R, msg: &[u8]) -> (Self, BlindingFactor)
+    where
+        R: RngCore + CryptoRng,
+    {
+        let mut r = BlsScalar::random(&mut *rng);
+        while r == BlsScalar::zero() {
+            r = BlsScalar::random(&mut *rng);
+        }
+
+        let h = h0_htc(BLIND_DST, msg);
+        (Self((h * r).into()), BlindingFactor(r))
+    }
+}
+
+impl Serializable<48> for BlindedMessage {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        Ok(Self(G1Affine::from_bytes(bytes)?))
+    }
+}
+
+/// The signature over a [`BlindedMessage`], to be sent back to the client.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv-impl",
+    derive(Archive, Deserialize, Serialize),
+    archive_attr(derive(bytecheck::CheckBytes))
+)]
+pub struct BlindSignature(G1Affine);
+
+impl BlindSignature {
+    /// Verify the blind signature over the [`BlindedMessage`] before
+    /// unblinding it: e(sig, g_2) == e(blinded, pk).
+    pub fn verify(
+        &self,
+        pk: &PublicKey,
+        blinded: &BlindedMessage,
+    ) -> Result<(), Error> {
+        if !pk.is_valid() {
+            return Err(Error::InvalidSignature);
+        }
+
+        groups::verify_hashed::<MinSig>(&pk.0, &self.0, &blinded.0)
+    }
+
+    /// Unblind the signature into the [`Signature`] over the original
+    /// message.
+    pub fn unblind(&self, factor: &BlindingFactor) -> Signature {
+        let r_inv = factor
+            .0
+            .invert()
+            .expect("the blinding factor is never zero");
+
+        Signature((self.0 * r_inv).into())
+    }
+}
+
+impl Serializable<48> for BlindSignature {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        Ok(Self(G1Affine::from_bytes(bytes)?))
+    }
+}
+
+impl SecretKey {
+    /// Sign a [`BlindedMessage`], producing a [`BlindSignature`].
+    pub fn sign_blinded(&self, blinded: &BlindedMessage) -> BlindSignature {
+        BlindSignature(groups::sign_hashed::<MinSig>(&self.0, &blinded.0))
+    }
+}
+
+impl PublicKey {
+    /// Verify the [`Signature`] over `msg` unblinded from a
+    /// [`BlindSignature`].
+    ///
+    /// The public key must be valid, since the identity would accept the
+    /// identity as signature over any message.
+    pub fn verify_unblinded(
+        &self,
+        sig: &Signature,
+        msg: &[u8],
+    ) -> Result<(), Error> {
+        if !self.is_valid() {
+            return Err(Error::InvalidSignature);
+        }
+
+        let h = h0_htc(BLIND_DST, msg);
+        groups::verify_hashed::<MinSig>(&self.0, &sig.0, &h)
+    }
+}
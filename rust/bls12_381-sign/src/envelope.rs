@@ -0,0 +1,229 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Signed statements with a validity window and replay protection.
+//!
+//! A [`SignedEnvelope`] carries a [`Signable`] payload along with the
+//! [`PublicKey`] of its signer, the window of time in which it is valid and a
+//! nonce. Times are expressed in a unit of the caller's choice, e.g. seconds
+//! since the Unix epoch, and must be consistent between signing and
+//! verification.
+
+use crate::{Error, PublicKey, SecretKey, Signable, Signature, APK};
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use dusk_bytes::Serializable;
+
+/// Domain tag of the statements signed in an envelope.
+const ENVELOPE_DST: &[u8] = b"DUSK_BLS12381_ENVELOPE";
+
+/// Record of the nonces used by each signer, rejecting replayed envelopes.
+pub trait NonceStore {
+    /// Record `nonce` as used by `signer`, returning `false` if it already
+    /// was.
+    ///
+    /// The envelope carrying the nonce is valid until `not_after`, after
+    /// which the store may forget it.
+    fn insert(
+        &mut self,
+        signer: &PublicKey,
+        nonce: u64,
+        not_after: u64,
+    ) -> bool;
+}
+
+/// A [`NonceStore`] holding the nonces in memory.
+#[derive(Default, Clone, Debug)]
+pub struct MemoryNonceStore {
+    nonces: BTreeMap<([u8; PublicKey::SIZE], u64), u64>,
+}
+
+impl MemoryNonceStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget the nonces of the envelopes expired at time `now`.
+    pub fn prune(&mut self, now: u64) {
+        self.nonces.retain(|_, not_after| *not_after >= now);
+    }
+
+    /// Number of nonces held by the store.
+    pub fn len(&self) -> usize {
+        self.nonces.len()
+    }
+
+    /// Whether the store holds no nonce.
+    pub fn is_empty(&self) -> bool {
+        self.nonces.is_empty()
+    }
+}
+
+impl NonceStore for MemoryNonceStore {
+    fn insert(
+        &mut self,
+        signer: &PublicKey,
+        nonce: u64,
+        not_after: u64,
+    ) -> bool {
+        let key = (signer.to_bytes(), nonce);
+        if self.nonces.contains_key(&key) {
+            return false;
+        }
+
+        self.nonces.insert(key, not_after);
+        true
+    }
+}
+
+/// A payload signed along with its signer, validity window and nonce.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedEnvelope<T> {
+    payload: T,
+    signer: PublicKey,
+    not_before: u64,
+    not_after: u64,
+    nonce: u64,
+    sig: Signature,
+}
+
+impl<T: Signable> SignedEnvelope<T> {
+    /// Sign `payload` with `sk`, valid from `not_before` to `not_after`
+    /// included.
+    ///
+    /// The nonce must be unique among the envelopes signed with `sk` for
+    /// them not to be rejected as replays.
+    pub fn new(
+        sk: &SecretKey,
+        payload: T,
+        not_before: u64,
+        not_after: u64,
+        nonce: u64,
+    ) -> Self {
+        let signer = PublicKey::from(sk);
+        let msg = Self::signing_bytes(
+            &payload, &signer, not_before, not_after, nonce,
+        );
+        let sig = sk.sign(&signer, &msg);
+
+        Self {
+            payload,
+            signer,
+            not_before,
+            not_after,
+            nonce,
+            sig,
+        }
+    }
+
+    /// Create an envelope from its parts, e.g. after receiving them.
+    pub fn from_parts(
+        payload: T,
+        signer: PublicKey,
+        not_before: u64,
+        not_after: u64,
+        nonce: u64,
+        sig: Signature,
+    ) -> Self {
+        Self {
+            payload,
+            signer,
+            not_before,
+            not_after,
+            nonce,
+            sig,
+        }
+    }
+
+    /// Verify the envelope at time `now`, recording its nonce in `store`.
+    ///
+    /// Fails with [`Error::NotYetValid`] or [`Error::Expired`] if `now` is
+    /// outside of the validity window, with [`Error::InvalidSignature`] if
+    /// the signature is invalid, and with [`Error::Replay`] if the nonce was
+    /// already used by the signer. The nonce is only recorded when all the
+    /// other checks pass.
+    pub fn verify<S: NonceStore>(
+        &self,
+        now: u64,
+        store: &mut S,
+    ) -> Result<(), Error> {
+        if now < self.not_before {
+            return Err(Error::NotYetValid);
+        }
+        if now > self.not_after {
+            return Err(Error::Expired);
+        }
+
+        if !self.signer.is_valid() {
+            return Err(Error::InvalidSignature);
+        }
+        let msg = Self::signing_bytes(
+            &self.payload,
+            &self.signer,
+            self.not_before,
+            self.not_after,
+            self.nonce,
+        );
+        APK::from(&self.signer).verify(&self.sig, &msg)?;
+
+        if !store.insert(&self.signer, self.nonce, self.not_after) {
+            return Err(Error::Replay);
+        }
+
+        Ok(())
+    }
+
+    fn signing_bytes(
+        payload: &T,
+        signer: &PublicKey,
+        not_before: u64,
+        not_after: u64,
+        nonce: u64,
+    ) -> Vec<u8> {
+        (signer, not_before, not_after, nonce, payload)
+            .signing_bytes(ENVELOPE_DST)
+    }
+}
+
+impl<T> SignedEnvelope<T> {
+    /// The signed payload.
+    pub fn payload(&self) -> &T {
+        &self.payload
+    }
+
+    /// The [`PublicKey`] of the signer.
+    pub fn signer(&self) -> &PublicKey {
+        &self.signer
+    }
+
+    /// The time from which the envelope is valid.
+    pub fn not_before(&self) -> u64 {
+        self.not_before
+    }
+
+    /// The time until which the envelope is valid.
+    pub fn not_after(&self) -> u64 {
+        self.not_after
+    }
+
+    /// The nonce of the envelope.
+    pub fn nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    /// The [`Signature`] of the envelope.
+    pub fn signature(&self) -> &Signature {
+        &self.sig
+    }
+
+    /// Consume the envelope, returning its payload.
+    pub fn into_payload(self) -> T {
+        self.payload
+    }
+}
@@ -9,12 +9,37 @@ use dusk_bytes::Error as DuskBytesError;
 use core::fmt;
 
 /// Standard error for the interface
+///
+/// New variants may be added in minor releases, so matches on it must have a
+/// wildcard arm.
 #[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
 pub enum Error {
     /// Dusk-bytes serialization error
     BytesError(DuskBytesError),
     /// Cryptographic invalidity
     InvalidSignature,
+    /// The threshold is zero or greater than the number of shares
+    InvalidThreshold,
+    /// The shares are insufficient, inconsistent, or don't recover the
+    /// expected key
+    InvalidShares,
+    /// The ciphertext can't be decrypted with the given key
+    InvalidCiphertext,
+    /// The statement isn't valid yet
+    NotYetValid,
+    /// The statement is no longer valid
+    Expired,
+    /// The nonce of the statement was already used by its signer
+    Replay,
+    /// A different message was already signed for the same round and step
+    DoubleSign,
+    /// The slashing protection records can't be read or written
+    Storage,
+    /// The slashing protection interchange data is malformed
+    InvalidInterchange,
+    /// A target group element can't be encoded
+    Encoding,
 }
 
 impl From<DuskBytesError> for Error {
@@ -30,6 +55,16 @@ impl fmt::Display for Error {
             Self::InvalidSignature => {
                 write!(f, "Invalid Signature")
             }
+            Self::InvalidThreshold => write!(f, "Invalid Threshold"),
+            Self::InvalidShares => write!(f, "Invalid Shares"),
+            Self::InvalidCiphertext => write!(f, "Invalid Ciphertext"),
+            Self::NotYetValid => write!(f, "Not Yet Valid"),
+            Self::Expired => write!(f, "Expired"),
+            Self::Replay => write!(f, "Replay"),
+            Self::DoubleSign => write!(f, "Double Sign"),
+            Self::Storage => write!(f, "Storage Error"),
+            Self::InvalidInterchange => write!(f, "Invalid Interchange"),
+            Self::Encoding => write!(f, "Encoding Error"),
         }
     }
 }
@@ -25,6 +25,7 @@ impl From<Error> for c_int {
         match e {
             Error::BytesError(_) => 1,
             Error::InvalidSignature => 2,
+            _ => 3,
         }
     }
 }
@@ -0,0 +1,215 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Modified BLS operations, generic over the groups holding the public keys
+//! and the signatures.
+
+#[cfg(feature = "hash-to-curve")]
+use crate::hash::{h0_g2, h1};
+use crate::hash::{h0_with, h1_with, HashToScalar, LegacyBlake2b};
+use crate::Error;
+
+use core::iter::Sum;
+use core::marker::PhantomData;
+use core::ops::{Add, Mul};
+
+use dusk_bls12_381::{
+    pairing, BlsScalar, G1Affine, G1Projective, G2Affine, G2Projective,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Assignment of the public keys and the signatures to the BLS12-381 groups.
+pub(crate) trait Groups {
+    /// Affine point of the public keys group.
+    type Pk: Copy + Send + Sync + Mul<BlsScalar, Output = Self::PkProj>;
+    /// Projective point of the public keys group.
+    type PkProj: Copy
+        + Send
+        + From<Self::Pk>
+        + Into<Self::Pk>
+        + Add<Output = Self::PkProj>
+        + Sum;
+    /// Affine point of the signatures group.
+    type Sig: Copy + Mul<BlsScalar, Output = Self::SigProj>;
+    /// Projective point of the signatures group.
+    type SigProj: Copy
+        + From<Self::Sig>
+        + Into<Self::Sig>
+        + Add<Output = Self::SigProj>;
+
+    /// Base point of the public keys group.
+    fn pk_generator() -> Self::Pk;
+
+    /// Hash a message into the signatures group.
+    fn hash_to_sig(msg: &[u8]) -> Self::Sig;
+
+    /// Hash a public key into a scalar, as in the modified BLS construction.
+    fn hash_pk(pk: &Self::Pk) -> BlsScalar;
+
+    /// Compare the results of the two pairing operations of the verification:
+    /// e(sig, g) == e(h, pk), with the arguments of each pairing ordered
+    /// according to their group.
+    fn pairing_eq(pk: &Self::Pk, sig: &Self::Sig, h: &Self::Sig) -> bool;
+}
+
+/// Public keys in G2 and signatures in G1, minimizing the signature size.
+/// Messages and public keys are hashed with `H`.
+pub(crate) struct MinSig<H = LegacyBlake2b>(PhantomData<H>);
+
+impl<H: HashToScalar> Groups for MinSig<H> {
+    type Pk = G2Affine;
+    type PkProj = G2Projective;
+    type Sig = G1Affine;
+    type SigProj = G1Projective;
+
+    fn pk_generator() -> G2Affine {
+        G2Affine::generator()
+    }
+
+    fn hash_to_sig(msg: &[u8]) -> G1Affine {
+        h0_with::<H>(msg)
+    }
+
+    fn hash_pk(pk: &G2Affine) -> BlsScalar {
+        h1_with::<H, 96, _>(pk)
+    }
+
+    fn pairing_eq(pk: &G2Affine, sig: &G1Affine, h: &G1Affine) -> bool {
+        pairing(sig, &G2Affine::generator()) == pairing(h, pk)
+    }
+}
+
+/// Public keys in G1 and signatures in G2, minimizing the public key size.
+#[cfg(feature = "hash-to-curve")]
+pub(crate) struct MinPk;
+
+#[cfg(feature = "hash-to-curve")]
+impl Groups for MinPk {
+    type Pk = G1Affine;
+    type PkProj = G1Projective;
+    type Sig = G2Affine;
+    type SigProj = G2Projective;
+
+    fn pk_generator() -> G1Affine {
+        G1Affine::generator()
+    }
+
+    fn hash_to_sig(msg: &[u8]) -> G2Affine {
+        h0_g2(msg)
+    }
+
+    fn hash_pk(pk: &G1Affine) -> BlsScalar {
+        h1(pk)
+    }
+
+    fn pairing_eq(pk: &G1Affine, sig: &G2Affine, h: &G2Affine) -> bool {
+        pairing(&G1Affine::generator(), sig) == pairing(pk, h)
+    }
+}
+
+/// pk = g * sk
+pub(crate) fn public_key<G: Groups>(sk: &BlsScalar) -> G::Pk {
+    (G::pk_generator() * *sk).into()
+}
+
+/// Return pk * t, where t is H_(pk).
+pub(crate) fn pk_t<G: Groups>(pk: &G::Pk) -> G::Pk {
+    (*pk * G::hash_pk(pk)).into()
+}
+
+/// sig = Hₒ(m) * sk
+pub(crate) fn sign_vulnerable<G: Groups>(sk: &BlsScalar, msg: &[u8]) -> G::Sig {
+    sign_hashed::<G>(sk, &G::hash_to_sig(msg))
+}
+
+/// sig = h * sk, for a message already hashed to the point h.
+pub(crate) fn sign_hashed<G: Groups>(sk: &BlsScalar, h: &G::Sig) -> G::Sig {
+    (*h * *sk).into()
+}
+
+/// sig = Hₒ(m) * sk * t, where t is H_(pk).
+pub(crate) fn sign<G: Groups>(
+    sk: &BlsScalar,
+    pk: &G::Pk,
+    msg: &[u8],
+) -> G::Sig {
+    sign_prehashed::<G>(sk, pk, &G::hash_to_sig(msg))
+}
+
+/// sig = h * sk * t, where t is H_(pk), for a message already hashed to the
+/// point h.
+pub(crate) fn sign_prehashed<G: Groups>(
+    sk: &BlsScalar,
+    pk: &G::Pk,
+    h: &G::Sig,
+) -> G::Sig {
+    let sig = sign_hashed::<G>(sk, h);
+
+    // Turn signature into its modified construction,
+    // which provides protection against rogue-key attacks.
+    (sig * G::hash_pk(pk)).into()
+}
+
+/// Verify a signature by comparing the results of the two pairing
+/// operations: e(sig, g) == e(Hₒ(m), pk).
+pub(crate) fn verify<G: Groups>(
+    pk: &G::Pk,
+    sig: &G::Sig,
+    msg: &[u8],
+) -> Result<(), Error> {
+    verify_hashed::<G>(pk, sig, &G::hash_to_sig(msg))
+}
+
+/// Verify a signature over a message already hashed to the point h:
+/// e(sig, g) == e(h, pk).
+pub(crate) fn verify_hashed<G: Groups>(
+    pk: &G::Pk,
+    sig: &G::Sig,
+    h: &G::Sig,
+) -> Result<(), Error> {
+    if G::pairing_eq(pk, sig, h) {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
+
+/// Add the public keys to the aggregated public key, each multiplied by its
+/// H_(pk). The `point` function extracts the point from each public key.
+pub(crate) fn aggregate_pks<G, P, F>(apk: &G::Pk, pks: &[P], point: F) -> G::Pk
+where
+    G: Groups,
+    P: Sync,
+    F: Fn(&P) -> &G::Pk + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    let iter = pks.par_iter();
+
+    #[cfg(not(feature = "parallel"))]
+    let iter = pks.iter();
+
+    let sum: G::PkProj =
+        iter.map(|pk| G::PkProj::from(pk_t::<G>(point(pk)))).sum();
+    (G::PkProj::from(*apk) + sum).into()
+}
+
+/// Aggregate a set of signatures by simply adding up the points. The `point`
+/// function extracts the point from each signature.
+pub(crate) fn aggregate_sigs<G, S, F>(
+    sig: &G::Sig,
+    sigs: &[S],
+    point: F,
+) -> G::Sig
+where
+    G: Groups,
+    F: Fn(&S) -> &G::Sig,
+{
+    sigs.iter().fold(*sig, |acc, sig| {
+        (G::SigProj::from(acc) + G::SigProj::from(*point(sig))).into()
+    })
+}
@@ -6,19 +6,62 @@
 
 //! Defines the hash functions needed for the BLS signature scheme.
 
+#[cfg(feature = "hash-to-curve")]
 use crate::PublicKey;
 
+#[cfg(any(feature = "poseidon", feature = "hash-to-curve"))]
+use alloc::vec::Vec;
+
 use blake2::digest::consts::U32;
 use blake2::Digest;
+#[cfg(feature = "hash-to-curve")]
+use dusk_bls12_381::hash_to_curve::{ExpandMsgXmd, HashToCurve};
 use dusk_bls12_381::{BlsScalar, G1Affine};
+#[cfg(feature = "hash-to-curve")]
+use dusk_bls12_381::{G1Projective, G2Affine, G2Projective};
 use dusk_bytes::Serializable;
 
 type Blake2b = blake2::Blake2b<U32>;
 
-/// Hash an arbitrary slice of bytes into a [`BlsScalar`]
-fn h(msg: &[u8]) -> BlsScalar {
-    let mut digest: [u8; BlsScalar::SIZE] = Blake2b::digest(msg).into();
+/// Incremental hash of arbitrary bytes into a [`BlsScalar`], used both to hash
+/// messages into the signatures group and public keys in the modified BLS
+/// construction.
+pub trait HashToScalar: Default {
+    /// Append `data` to the input being hashed.
+    fn update(&mut self, data: &[u8]);
+
+    /// Consume the hasher, returning the input hashed into a [`BlsScalar`].
+    fn finalize(self) -> BlsScalar;
+
+    /// Hash an arbitrary slice of bytes into a [`BlsScalar`].
+    fn hash(data: &[u8]) -> BlsScalar {
+        let mut hasher = Self::default();
+        hasher.update(data);
+        hasher.finalize()
+    }
+}
+
+/// The original hash-to-scalar of this crate: Blake2b-256 with the two top
+/// bits of the digest cleared.
+///
+/// The resulting scalar is biased, but this is the hash used by every API not
+/// taking a [`HashToScalar`] parameter, and is kept for compatibility with
+/// the existing keys and signatures.
+#[derive(Default, Clone, Debug)]
+pub struct LegacyBlake2b(Blake2b);
 
+impl HashToScalar for LegacyBlake2b {
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> BlsScalar {
+        digest_to_scalar(self.0.finalize().into())
+    }
+}
+
+/// Reduce a Blake2b digest into a [`BlsScalar`]
+fn digest_to_scalar(mut digest: [u8; BlsScalar::SIZE]) -> BlsScalar {
     // Truncate the contract id to fit bls
     digest[31] &= 0x3f;
 
@@ -26,17 +69,406 @@ fn h(msg: &[u8]) -> BlsScalar {
     hash.unwrap_or_default()
 }
 
+/// Hash-to-scalar over any [`Digest`] with an output of at least 32 bytes,
+/// reducing 64 bytes of output modulo the scalar field order so that the
+/// resulting scalar is unbiased.
+///
+/// Digests with an output shorter than 64 bytes are computed twice, over the
+/// input prefixed with a `0` and with a `1` byte, and the two outputs
+/// concatenated.
+#[derive(Clone, Debug)]
+pub struct WideDigest<D> {
+    lo: D,
+    hi: Option<D>,
+}
+
+impl<D: Digest> Default for WideDigest<D> {
+    fn default() -> Self {
+        let wide = <D as Digest>::output_size() >= 64;
+
+        let mut lo = D::new();
+        let mut hi = D::new();
+
+        lo.update([0]);
+        hi.update([1]);
+
+        Self {
+            lo,
+            hi: (!wide).then_some(hi),
+        }
+    }
+}
+
+impl<D: Digest> HashToScalar for WideDigest<D> {
+    fn update(&mut self, data: &[u8]) {
+        self.lo.update(data);
+        if let Some(hi) = &mut self.hi {
+            hi.update(data);
+        }
+    }
+
+    fn finalize(self) -> BlsScalar {
+        let mut bytes = [0u8; 64];
+
+        let lo = self.lo.finalize();
+        match self.hi {
+            None => bytes.copy_from_slice(&lo[..64]),
+            Some(hi) => {
+                let hi = hi.finalize();
+                bytes[..32].copy_from_slice(&lo[..32]);
+                bytes[32..].copy_from_slice(&hi[..32]);
+            }
+        }
+
+        BlsScalar::from_bytes_wide(&bytes)
+    }
+}
+
+/// Blake2b-512 with wide reduction.
+pub type Blake2bWide = WideDigest<blake2::Blake2b512>;
+
+/// SHA-256 with wide reduction.
+#[cfg(feature = "sha2")]
+pub type Sha256Wide = WideDigest<sha2::Sha256>;
+
+/// BLAKE3 with wide reduction.
+#[cfg(feature = "blake3")]
+pub type Blake3Wide = WideDigest<blake3::Hasher>;
+
+/// Width of the state of the Poseidon sponge, its first scalar being the
+/// capacity and the others the rate.
+#[cfg(feature = "poseidon")]
+const WIDTH: usize = 5;
+
+/// Poseidon sponge over the scalar field, cheap to compute in a circuit.
+///
+/// The input bytes are split in chunks of 31 bytes, each encoded as a
+/// [`BlsScalar`], and hashed followed by the length of the input. The chunks
+/// are absorbed as the input is updated, so only the last partial chunk is
+/// buffered.
+#[cfg(feature = "poseidon")]
+#[derive(Clone, Debug)]
+pub struct Poseidon {
+    state: [BlsScalar; WIDTH],
+    /// Scalars absorbed in the rate of the state since the last permutation
+    absorbed: usize,
+    /// Bytes of the chunk being filled
+    chunk: [u8; Poseidon::CHUNK_SIZE],
+    /// Length of the input so far
+    len: u64,
+}
+
+#[cfg(feature = "poseidon")]
+impl Default for Poseidon {
+    fn default() -> Self {
+        Self {
+            state: [BlsScalar::zero(); WIDTH],
+            absorbed: 0,
+            chunk: [0; Self::CHUNK_SIZE],
+            len: 0,
+        }
+    }
+}
+
+#[cfg(feature = "poseidon")]
+impl Poseidon {
+    /// Number of bytes of input encoded in each scalar.
+    pub const CHUNK_SIZE: usize = 31;
+
+    /// Encode bytes into the scalars input of the sponge.
+    pub fn input_scalars(data: &[u8]) -> Vec<BlsScalar> {
+        let mut scalars = Vec::with_capacity(2 + data.len() / Self::CHUNK_SIZE);
+        scalars.extend(data.chunks(Self::CHUNK_SIZE).map(chunk_to_scalar));
+        scalars.push(BlsScalar::from(data.len() as u64));
+
+        scalars
+    }
+
+    /// Absorb a scalar into the rate of the state, permuting it once full.
+    fn absorb(&mut self, scalar: BlsScalar) {
+        self.state[1 + self.absorbed] += scalar;
+        self.absorbed += 1;
+
+        if self.absorbed == WIDTH - 1 {
+            permute(&mut self.state);
+            self.absorbed = 0;
+        }
+    }
+
+    /// Bytes of the chunk being filled.
+    fn filled(&self) -> usize {
+        self.len as usize % Self::CHUNK_SIZE
+    }
+}
+
+/// Apply the Hades permutation of the Poseidon sponge to a state.
+///
+/// dusk-hades is deprecated in favour of dusk-poseidon, which doesn't expose
+/// the permutation in the version used here.
+#[cfg(feature = "poseidon")]
+#[allow(deprecated)]
+fn permute(state: &mut [BlsScalar; WIDTH]) {
+    use dusk_hades::{ScalarStrategy, Strategy};
+
+    ScalarStrategy::new().perm(state);
+}
+
+/// Encode up to 31 bytes into a [`BlsScalar`].
+#[cfg(feature = "poseidon")]
+fn chunk_to_scalar(chunk: &[u8]) -> BlsScalar {
+    let mut bytes = [0u8; BlsScalar::SIZE];
+    bytes[..chunk.len()].copy_from_slice(chunk);
+    // 31 bytes are always below the scalar field order.
+    BlsScalar::from_bytes(&bytes).unwrap()
+}
+
+#[cfg(feature = "poseidon")]
+impl HashToScalar for Poseidon {
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let filled = self.filled();
+            let n = (Self::CHUNK_SIZE - filled).min(data.len());
+            self.chunk[filled..filled + n].copy_from_slice(&data[..n]);
+            self.len += n as u64;
+            data = &data[n..];
+
+            if self.filled() == 0 {
+                let scalar = chunk_to_scalar(&self.chunk);
+                self.absorb(scalar);
+            }
+        }
+    }
+
+    /// Same as `dusk_poseidon::sponge::hash` over the
+    /// [`Poseidon::input_scalars`] of the input.
+    fn finalize(mut self) -> BlsScalar {
+        let filled = self.filled();
+        if filled > 0 {
+            let scalar = chunk_to_scalar(&self.chunk[..filled]);
+            self.absorb(scalar);
+        }
+        self.absorb(BlsScalar::from(self.len));
+
+        // The padding is a `1` after the input, in a block of its own if the
+        // last one is full.
+        self.state[1 + self.absorbed] += BlsScalar::one();
+        permute(&mut self.state);
+
+        self.state[1]
+    }
+}
+
 /// h0 is the hash-to-curve-point function.
 /// Hₒ : M -> Gₒ
 pub fn h0(msg: &[u8]) -> G1Affine {
+    h0_with::<LegacyBlake2b>(msg)
+}
+
+/// h0_with is the hash-to-curve-point function over the given hash-to-scalar.
+/// Hₒ : M -> Gₒ
+pub fn h0_with<H: HashToScalar>(msg: &[u8]) -> G1Affine {
     // Now multiply this message by the G1 base point,
     // to generate a G1Affine.
-    (G1Affine::generator() * h(msg)).into()
+    (G1Affine::generator() * H::hash(msg)).into()
+}
+
+/// Incremental hasher of a message into the signatures group, producing the
+/// same point as Hₒ over the concatenation of all the data fed to it.
+///
+/// This allows signing and verifying messages too large to be held in memory
+/// at once, with `SecretKey::sign_prehashed` and `APK::verify_prehashed`.
+///
+/// The hasher uses [`LegacyBlake2b`] unless another [`HashToScalar`] is
+/// given, in which case the message point matches the one of the `*_with`
+/// functions using the same hash.
+#[derive(Default, Clone, Debug)]
+pub struct MessageHasher<H = LegacyBlake2b>(H);
+
+impl MessageHasher {
+    /// Create a new hasher for an empty message.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<H: HashToScalar> MessageHasher<H> {
+    /// Append `data` to the message being hashed.
+    pub fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    /// Consume the hasher, returning the message hashed into G1.
+    pub fn finalize(self) -> HashedMessage {
+        HashedMessage((G1Affine::generator() * self.0.finalize()).into())
+    }
+}
+
+/// A message hashed into G1 by a [`MessageHasher`].
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct HashedMessage(pub(crate) G1Affine);
+
+impl From<&[u8]> for HashedMessage {
+    /// Hash a message held in memory, equivalently to a [`MessageHasher`]
+    /// fed with the whole message.
+    fn from(msg: &[u8]) -> Self {
+        Self(h0(msg))
+    }
+}
+
+/// h0_htc is the hash-to-curve-point function of the IETF hash-to-curve
+/// draft, with the `BLS12381G1_XMD:SHA-256_SSWU_RO_` suite, under a domain
+/// separation tag.
+///
+/// Unlike with [`h0`], the discrete logarithm of the points is unknown, so a
+/// signature over one point doesn't give away the signature over any other.
+/// The schemes relying on the unpredictability of signatures hash with it.
+/// Hₒ : M -> Gₒ
+#[cfg(feature = "hash-to-curve")]
+pub fn h0_htc(dst: &[u8], msg: &[u8]) -> G1Affine {
+    <G1Projective as HashToCurve<ExpandMsgXmd<htc_sha2::Sha256>>>::hash_to_curve(
+        msg, dst,
+    )
+    .into()
+}
+
+/// Domain separation tag of the messages hashed into G2, that of the
+/// `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_` ciphersuite of the IETF BLS
+/// signature draft.
+#[cfg(feature = "hash-to-curve")]
+const G2_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+
+/// h0_htc_g2 is the hash-to-curve-point function of the IETF hash-to-curve
+/// draft into G2, with the `BLS12381G2_XMD:SHA-256_SSWU_RO_` suite, under a
+/// domain separation tag.
+/// Hₒ : M -> G₂
+#[cfg(feature = "hash-to-curve")]
+pub fn h0_htc_g2(dst: &[u8], msg: &[u8]) -> G2Affine {
+    <G2Projective as HashToCurve<ExpandMsgXmd<htc_sha2::Sha256>>>::hash_to_curve(
+        msg, dst,
+    )
+    .into()
+}
+
+/// h0_g2 is the hash-to-curve-point function for signatures in G2, hashing
+/// with [`h0_htc_g2`] under the tag of the ciphersuite.
+///
+/// The discrete logarithm of the points is unknown, so a signature in G2
+/// doesn't give away the secret key times the generator of G2, which would
+/// forge the signatures in G1 of the same key.
+/// Hₒ : M -> G₂
+#[cfg(feature = "hash-to-curve")]
+pub fn h0_g2(msg: &[u8]) -> G2Affine {
+    h0_htc_g2(G2_DST, msg)
+}
+
+/// Domain separation tag of the message augmentation scheme, that of the
+/// `BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_AUG_` ciphersuite of the IETF BLS
+/// signature draft.
+#[cfg(feature = "hash-to-curve")]
+const AUG_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_AUG_";
+
+/// h0_aug is the hash-to-curve-point function of the message augmentation
+/// scheme of the IETF BLS signature draft, hashing the message prepended with
+/// the signer's public key with [`h0_htc`] under the tag of the ciphersuite.
+/// Hₒ : G₂ x M -> Gₒ
+#[cfg(feature = "hash-to-curve")]
+pub fn h0_aug(pk: &PublicKey, msg: &[u8]) -> G1Affine {
+    let mut augmented = Vec::with_capacity(PublicKey::SIZE + msg.len());
+    augmented.extend_from_slice(&pk.to_bytes());
+    augmented.extend_from_slice(msg);
+
+    h0_htc(AUG_DST, &augmented)
 }
 
 /// h1 is the hashing function used in the modified BLS
-/// multi-signature construction.
-/// H₁ : G₂ -> R
-pub fn h1(pk: &PublicKey) -> BlsScalar {
-    h(&pk.to_bytes())
+/// multi-signature construction, over the serialized public key.
+/// H₁ : G₂ -> R, or H₁ : G₁ -> R for public keys in G1
+#[cfg(feature = "hash-to-curve")]
+pub fn h1<const N: usize, T: Serializable<N>>(pk: &T) -> BlsScalar {
+    h1_with::<LegacyBlake2b, N, T>(pk)
+}
+
+/// h1_with is the hashing function of the modified BLS multi-signature
+/// construction over the given hash-to-scalar.
+/// H₁ : G₂ -> R, or H₁ : G₁ -> R for public keys in G1
+pub fn h1_with<H, const N: usize, T>(pk: &T) -> BlsScalar
+where
+    H: HashToScalar,
+    T: Serializable<N>,
+{
+    H::hash(&pk.to_bytes())
+}
+
+#[cfg(all(test, feature = "hash-to-curve"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn h0_htc_vectors() {
+        // Vectors of the `BLS12381G1_XMD:SHA-256_SSWU_RO_` suite of the IETF
+        // hash-to-curve draft, as the uncompressed coordinates of the points.
+        const DST: &[u8] =
+            b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+        let cases: [(&[u8], &str, &str); 3] = [
+            (
+                b"",
+                "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+                "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+            ),
+            (
+                b"abc",
+                "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+                "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+            ),
+            (
+                b"abcdef0123456789",
+                "11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98",
+                "03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709",
+            ),
+        ];
+
+        for (msg, x, y) in cases {
+            let point = h0_htc(DST, msg).to_uncompressed();
+            assert_eq!(hex::encode(&point[..48]), x);
+            assert_eq!(hex::encode(&point[48..]), y);
+        }
+    }
+
+    #[test]
+    fn h0_g2_vectors() {
+        // Vectors of the `BLS12381G2_XMD:SHA-256_SSWU_RO_` suite of the IETF
+        // hash-to-curve draft, as the uncompressed coordinates of the points.
+        const DST: &[u8] =
+            b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+        let cases: [(&[u8], [&str; 4]); 2] = [
+            (
+                b"",
+                [
+                    "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d",
+                    "0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a",
+                    "12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6",
+                    "0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92",
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8",
+                    "02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6",
+                    "00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16",
+                    "1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48",
+                ],
+            ),
+        ];
+
+        for (msg, coordinates) in cases {
+            let point = h0_htc_g2(DST, msg).to_uncompressed();
+            for (i, expected) in coordinates.iter().enumerate() {
+                assert_eq!(
+                    hex::encode(&point[i * 48..(i + 1) * 48]),
+                    *expected
+                );
+            }
+        }
+    }
 }
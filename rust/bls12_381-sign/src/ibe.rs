@@ -0,0 +1,405 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Boneh-Franklin identity based encryption, with the Fujisaki-Okamoto
+//! transform for security against chosen ciphertext attacks.
+//!
+//! Messages are encrypted to a master [`PublicKey`] and an identity, and the
+//! decryption key of an identity is the [`Signature`] of the master
+//! [`SecretKey`] over it, as produced by `SecretKey::decryption_key`.
+//! Identities are hashed to the curve with `h0_htc`, so the decryption keys
+//! of some identities don't give away the keys of the others.
+//!
+//! Encrypting to the message of a future round of an unchained [`Beacon`]
+//! makes the ciphertext decryptable once the beacon reaches that round.
+//!
+//! [`Beacon`]: crate::Beacon
+
+use crate::groups::{self, MinSig};
+use crate::hash::h0_htc;
+use crate::{Error, PublicKey, SecretKey, Signature};
+
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use blake2::digest::consts::{U32, U64};
+use blake2::{Blake2b, Digest};
+use dusk_bls12_381::{pairing, BlsScalar, G1Affine, G2Affine, Gt};
+use dusk_bytes::{Error as DuskBytesError, Serializable};
+use rand_core::{CryptoRng, RngCore};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Domain separation tag of the identities hashed into G1.
+const IDENTITY_DST: &[u8] = b"DUSK_BLS12381G1_XMD:SHA-256_SSWU_RO_IBE_";
+/// Domain separation tag of the mask of the random seed.
+const MASK_DST: &[u8] = b"DUSK_BLS12381_IBE_MASK";
+/// Domain separation tag of the derivation of the encryption randomness.
+const NONCE_DST: &[u8] = b"DUSK_BLS12381_IBE_NONCE";
+/// Domain separation tag of the stream encrypting the message.
+const STREAM_DST: &[u8] = b"DUSK_BLS12381_IBE_STREAM";
+
+const SEED_SIZE: usize = 32;
+
+/// Size of a coefficient of the target group elements, over Fp.
+const FP_SIZE: usize = 48;
+/// Size of the encoding of a target group element, as its twelve
+/// coefficients over Fp.
+const GT_SIZE: usize = 12 * FP_SIZE;
+
+/// A message encrypted to an identity.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ciphertext {
+    u: G2Affine,
+    v: [u8; SEED_SIZE],
+    w: Vec<u8>,
+}
+
+impl Ciphertext {
+    /// Size of the serialized ciphertext, excluding the encrypted message.
+    pub const HEADER_SIZE: usize = G2Affine::SIZE + SEED_SIZE;
+
+    /// Serialize the ciphertext into `HEADER_SIZE` bytes followed by the
+    /// encrypted message.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::HEADER_SIZE + self.w.len());
+
+        bytes.extend_from_slice(&self.u.to_bytes());
+        bytes.extend_from_slice(&self.v);
+        bytes.extend_from_slice(&self.w);
+
+        bytes
+    }
+
+    /// Deserialize a ciphertext created with `Ciphertext::to_bytes`.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < Self::HEADER_SIZE {
+            return Err(DuskBytesError::BadLength {
+                found: bytes.len(),
+                expected: Self::HEADER_SIZE,
+            }
+            .into());
+        }
+
+        let (u, rest) = bytes.split_at(G2Affine::SIZE);
+        let (v, w) = rest.split_at(SEED_SIZE);
+
+        let mut u_bytes = [0u8; G2Affine::SIZE];
+        u_bytes.copy_from_slice(u);
+        let mut v_bytes = [0u8; SEED_SIZE];
+        v_bytes.copy_from_slice(v);
+
+        Ok(Self {
+            u: G2Affine::from_bytes(&u_bytes)?,
+            v: v_bytes,
+            w: w.to_vec(),
+        })
+    }
+}
+
+/// Hash an identity into G1.
+pub(crate) fn hash_identity(id: &[u8]) -> G1Affine {
+    h0_htc(IDENTITY_DST, id)
+}
+
+impl SecretKey {
+    /// The decryption key of the identity `id`: the [`Signature`] over the
+    /// identity hashed into G1.
+    pub fn decryption_key(&self, id: &[u8]) -> Signature {
+        Signature(groups::sign_hashed::<MinSig>(&self.0, &hash_identity(id)))
+    }
+}
+
+impl PublicKey {
+    /// Encrypt a message to the identity `id`, decryptable with the
+    /// decryption key of the corresponding [`SecretKey`] for `id`.
+    ///
+    /// Fails with [`Error::Encoding`] if the shared pairing value can't be
+    /// encoded.
+    pub fn encrypt<R>(
+        &self,
+        rng: &mut R,
+        id: &[u8],
+        msg: &[u8],
+    ) -> Result<Ciphertext, Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let mut seed = Zeroizing::new([0u8; SEED_SIZE]);
+        rng.fill_bytes(seed.as_mut());
+
+        let mut r = nonce(&seed, msg);
+        let u = G2Affine::from(G2Affine::generator() * r);
+        let shared = Zeroizing::new(pairing(&hash_identity(id), &self.0) * r);
+        r.zeroize();
+
+        let mut v = mask(&shared)?;
+        xor(&mut v, seed.as_ref());
+
+        let mut w = msg.to_vec();
+        xor(&mut w, &stream(&seed, msg.len()));
+
+        Ok(Ciphertext { u, v, w })
+    }
+}
+
+impl Signature {
+    /// Decrypt a [`Ciphertext`] encrypted to the identity the signature is
+    /// over.
+    ///
+    /// Fails with [`Error::InvalidCiphertext`] if the signature is not the
+    /// decryption key of the ciphertext, or if the ciphertext was tampered
+    /// with, and with [`Error::Encoding`] if the shared pairing value can't
+    /// be encoded.
+    pub fn decrypt(&self, ct: &Ciphertext) -> Result<Vec<u8>, Error> {
+        let shared = Zeroizing::new(pairing(&self.0, &ct.u));
+
+        let mut seed = Zeroizing::new(mask(&shared)?);
+        xor(seed.as_mut(), &ct.v);
+
+        let mut msg = Zeroizing::new(ct.w.clone());
+        xor(&mut msg, &stream(&seed, ct.w.len()));
+
+        let mut r = nonce(&seed, &msg);
+        let valid = G2Affine::from(G2Affine::generator() * r) == ct.u;
+        r.zeroize();
+
+        if !valid {
+            return Err(Error::InvalidCiphertext);
+        }
+
+        Ok(core::mem::take(&mut *msg))
+    }
+}
+
+/// Derive the encryption randomness from the seed and the message.
+fn nonce(seed: &[u8; SEED_SIZE], msg: &[u8]) -> BlsScalar {
+    let digest = Blake2b::<U64>::new()
+        .chain_update(NONCE_DST)
+        .chain_update(seed)
+        .chain_update(msg)
+        .finalize();
+
+    BlsScalar::from_bytes_wide(&digest.into())
+}
+
+/// Derive the mask of the seed from the shared pairing value.
+fn mask(shared: &Gt) -> Result<[u8; SEED_SIZE], Error> {
+    let shared = gt_to_bytes(shared)?;
+
+    Ok(Blake2b::<U32>::new()
+        .chain_update(MASK_DST)
+        .chain_update(shared.as_ref())
+        .finalize()
+        .into())
+}
+
+/// Encode a target group element as its twelve coefficients over Fp, from
+/// c0.c0.c0 to c1.c2.c1, each as 48 big-endian bytes.
+///
+/// `dusk-bls12_381` has no encoding of `Gt` and keeps its coefficients
+/// private, so they are taken from its formatting, which writes each of them
+/// with the canonical encoding of `Fp::to_bytes` as `0x` followed by 96 hex
+/// digits. The formatting is decoded as it's written by a [`GtEncoder`],
+/// without an intermediate string.
+fn gt_to_bytes(gt: &Gt) -> Result<Zeroizing<[u8; GT_SIZE]>, Error> {
+    let mut encoder = GtEncoder::default();
+    write!(encoder, "{:?}", gt).map_err(|_| Error::Encoding)?;
+
+    encoder.finish()
+}
+
+/// Decoder of the coefficients of a target group element out of its
+/// formatting.
+///
+/// The digits are decoded without branching on their value, and the bytes
+/// are wiped when dropped. Anything but exactly twelve coefficients of 96
+/// lowercase hex digits is rejected, so that a change to the formatting
+/// fails rather than silently deriving other masks, which would leave the
+/// existing ciphertexts undecryptable.
+struct GtEncoder {
+    bytes: Zeroizing<[u8; GT_SIZE]>,
+    /// Number of coefficients fully decoded.
+    coeffs: usize,
+    /// Number of digits decoded of the current coefficient, if any.
+    digits: Option<usize>,
+    /// Whether the last character is the end of a coefficient.
+    ended: bool,
+    /// Whether the last character outside the coefficients is a `0`.
+    zero: bool,
+    /// Whether a character of a coefficient isn't a lowercase hex digit.
+    invalid: u8,
+}
+
+impl Default for GtEncoder {
+    fn default() -> Self {
+        Self {
+            bytes: Zeroizing::new([0u8; GT_SIZE]),
+            coeffs: 0,
+            digits: None,
+            ended: false,
+            zero: false,
+            invalid: 0,
+        }
+    }
+}
+
+impl GtEncoder {
+    fn finish(self) -> Result<Zeroizing<[u8; GT_SIZE]>, Error> {
+        if self.coeffs != 12 || self.digits.is_some() || self.invalid != 0 {
+            return Err(Error::Encoding);
+        }
+
+        Ok(self.bytes)
+    }
+}
+
+impl Write for GtEncoder {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for &c in s.as_bytes() {
+            match self.digits {
+                Some(digits) => {
+                    let (nibble, invalid) = hex_digit(c);
+                    self.invalid |= invalid;
+
+                    let byte = self.coeffs * FP_SIZE + digits / 2;
+                    let shift = if digits % 2 == 0 { 4 } else { 0 };
+                    self.bytes[byte] |= nibble << shift;
+
+                    if digits + 1 == 2 * FP_SIZE {
+                        self.coeffs += 1;
+                        self.digits = None;
+                        self.ended = true;
+                    } else {
+                        self.digits = Some(digits + 1);
+                    }
+                }
+                None if self.zero && c == b'x' => {
+                    if self.coeffs == 12 {
+                        return Err(fmt::Error);
+                    }
+                    self.digits = Some(0);
+                    self.zero = false;
+                }
+                None => {
+                    // A coefficient longer than 96 digits.
+                    if self.ended && c.is_ascii_hexdigit() {
+                        return Err(fmt::Error);
+                    }
+                    self.ended = false;
+                    self.zero = c == b'0';
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Decode a lowercase hex digit, returning its value and `0xff` if the
+/// character isn't one, without branching on the character.
+fn hex_digit(c: u8) -> (u8, u8) {
+    let c = c as i16;
+
+    // -1 if the character is in the range, 0 otherwise.
+    let num = ((0x2f - c) & (c - 0x3a)) >> 8;
+    let alpha = ((0x60 - c) & (c - 0x67)) >> 8;
+
+    let value = ((c - 0x30) & num) | ((c - 0x61 + 10) & alpha);
+    let invalid = !(num | alpha);
+
+    (value as u8, invalid as u8)
+}
+
+/// Expand the seed into `len` bytes of key stream.
+fn stream(seed: &[u8; SEED_SIZE], len: usize) -> Vec<u8> {
+    let mut stream = Vec::with_capacity(len);
+
+    let mut counter = 0u32;
+    while stream.len() < len {
+        let block = Blake2b::<U64>::new()
+            .chain_update(STREAM_DST)
+            .chain_update(seed)
+            .chain_update(counter.to_be_bytes())
+            .finalize();
+
+        let n = core::cmp::min(block.len(), len - stream.len());
+        stream.extend_from_slice(&block[..n]);
+        counter += 1;
+    }
+
+    stream
+}
+
+fn xor(dst: &mut [u8], src: &[u8]) {
+    dst.iter_mut().zip(src).for_each(|(d, s)| *d ^= s);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gt_to_bytes_known_answers() {
+        let identity = gt_to_bytes(&Gt::identity()).expect("encodable");
+        assert_eq!(identity[FP_SIZE - 1], 1);
+        assert!(identity
+            .iter()
+            .enumerate()
+            .all(|(i, b)| i == FP_SIZE - 1 || *b == 0));
+
+        // The coefficients of e(g_1, g_2), the generator of the target group.
+        let generator = [
+            "1250ebd871fc0a92a7b2d83168d0d727272d441befa15c503dd8e90ce98db3e7b6d194f60839c508a84305aaca1789b6",
+            "089a1c5b46e5110b86750ec6a532348868a84045483c92b7af5af689452eafabf1a8943e50439f1d59882a98eaa0170f",
+            "1368bb445c7c2d209703f239689ce34c0378a68e72a6b3b216da0e22a5031b54ddff57309396b38c881c4c849ec23e87",
+            "193502b86edb8857c273fa075a50512937e0794e1e65a7617c90d8bd66065b1fffe51d7a579973b1315021ec3c19934f",
+            "01b2f522473d171391125ba84dc4007cfbf2f8da752f7c74185203fcca589ac719c34dffbbaad8431dad1c1fb597aaa5",
+            "018107154f25a764bd3c79937a45b84546da634b8f6be14a8061e55cceba478b23f7dacaa35c8ca78beae9624045b4b6",
+            "19f26337d205fb469cd6bd15c3d5a04dc88784fbb3d0b2dbdea54d43b2b73f2cbb12d58386a8703e0f948226e47ee89d",
+            "06fba23eb7c5af0d9f80940ca771b6ffd5857baaf222eb95a7d2809d61bfe02e1bfd1b68ff02f0b8102ae1c2d5d5ab1a",
+            "11b8b424cd48bf38fcef68083b0b0ec5c81a93b330ee1a677d0d15ff7b984e8978ef48881e32fac91b93b47333e2ba57",
+            "03350f55a7aefcd3c31b4fcb6ce5771cc6a0e9786ab5973320c806ad360829107ba810c5a09ffdd9be2291a0c25a99a2",
+            "04c581234d086a9902249b64728ffd21a189e87935a954051c7cdba7b3872629a4fafc05066245cb9108f0242d0fe3ef",
+            "0f41e58663bf08cf068672cbd01a7ec73baca4d72ca93544deff686bfd6df543d48eaa24afe47e1efde449383b676631",
+        ];
+        let bytes = gt_to_bytes(&pairing(
+            &G1Affine::generator(),
+            &G2Affine::generator(),
+        ))
+        .expect("encodable");
+        for (coeff, expected) in bytes.chunks(FP_SIZE).zip(generator) {
+            assert_eq!(hex::encode(coeff), expected);
+        }
+    }
+
+    #[test]
+    fn gt_encoder_rejects_other_formats() {
+        let digits = "0123456789abcdef".repeat(6);
+        let coeff = |digits: &str| alloc::format!("0x{}, ", digits);
+
+        let encode = |repr: &str| {
+            let mut encoder = GtEncoder::default();
+            encoder.write_str(repr).map_err(|_| Error::Encoding)?;
+            encoder.finish()
+        };
+
+        assert!(encode(&coeff(&digits).repeat(12)).is_ok());
+
+        // Too few or too many coefficients.
+        assert!(encode(&coeff(&digits).repeat(11)).is_err());
+        assert!(encode(&coeff(&digits).repeat(13)).is_err());
+
+        // A coefficient too short or too long.
+        let short = coeff(&digits[1..]) + &coeff(&digits).repeat(11);
+        assert!(encode(&short).is_err());
+        let long = coeff(&(digits.clone() + "0")) + &coeff(&digits).repeat(11);
+        assert!(encode(&long).is_err());
+
+        // Uppercase digits.
+        let upper = coeff(&digits.to_uppercase()) + &coeff(&digits).repeat(11);
+        assert!(encode(&upper).is_err());
+    }
+}
@@ -5,5 +5,10 @@
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
 pub mod apk;
+#[cfg(feature = "hash-to-curve")]
+pub mod apk_g1;
 pub mod public;
+#[cfg(feature = "hash-to-curve")]
+pub mod public_g1;
 pub mod secret;
+pub mod share;
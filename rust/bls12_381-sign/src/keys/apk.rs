@@ -4,18 +4,15 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use crate::{hash::h1, Signature};
-use crate::{Error, PublicKey, SecretKey};
+use crate::groups::{self, MinSig};
+use crate::hash::HashToScalar;
+use crate::{Error, HashedMessage, PublicKey, SecretKey, Signature};
 
-use dusk_bls12_381::G2Projective;
 use dusk_bytes::{Error as DuskBytesError, Serializable};
 
 #[cfg(feature = "rkyv-impl")]
 use rkyv::{Archive, Deserialize, Serialize};
 
-#[cfg(feature = "parallel")]
-use rayon::prelude::*;
-
 /// Aggregated form of a BLS public key.
 /// The public keys are aggregated in a rogue-key attack
 /// resistant manner, by using the hash function defined
@@ -26,7 +23,7 @@ use rayon::prelude::*;
     derive(Archive, Deserialize, Serialize),
     archive_attr(derive(bytecheck::CheckBytes))
 )]
-pub struct APK(PublicKey);
+pub struct APK(pub(crate) PublicKey);
 
 impl Serializable<96> for APK {
     type Error = DuskBytesError;
@@ -42,9 +39,7 @@ impl Serializable<96> for APK {
 
 impl From<&PublicKey> for APK {
     fn from(pk: &PublicKey) -> Self {
-        let t = h1(pk);
-        let gx = pk.0 * t;
-        Self(PublicKey(gx.into()))
+        Self(PublicKey(pk.pk_t()))
     }
 }
 
@@ -57,18 +52,32 @@ impl From<&SecretKey> for APK {
 }
 
 impl APK {
+    /// Create an [`APK`] from a [`PublicKey`], hashing it with `H` instead of
+    /// the default [`LegacyBlake2b`].
+    ///
+    /// An [`APK`] created this way must only be aggregated with
+    /// `aggregate_with` and verified with `verify_with` using the same hash.
+    ///
+    /// [`LegacyBlake2b`]: crate::LegacyBlake2b
+    pub fn from_with<H: HashToScalar>(pk: &PublicKey) -> Self {
+        Self(PublicKey(pk.pk_t_with::<H>()))
+    }
+
     /// Aggregate a set of [`PublicKey`] into the [`APK`].
     pub fn aggregate(&mut self, pks: &[PublicKey]) {
-        #[cfg(feature = "parallel")]
-        let iter = pks.par_iter();
-
-        #[cfg(not(feature = "parallel"))]
-        let iter = pks.iter();
+        (self.0).0 =
+            groups::aggregate_pks::<MinSig, _, _>(&(self.0).0, pks, |pk| &pk.0);
+    }
 
-        let sum: G2Projective = iter
-            .map(|pk| dusk_bls12_381::G2Projective::from(pk.pk_t()))
-            .sum();
-        (self.0).0 = ((self.0).0 + sum).into();
+    /// Aggregate a set of [`PublicKey`] into the [`APK`], hashing them with
+    /// `H` instead of the default [`LegacyBlake2b`].
+    ///
+    /// [`LegacyBlake2b`]: crate::LegacyBlake2b
+    pub fn aggregate_with<H: HashToScalar>(&mut self, pks: &[PublicKey]) {
+        (self.0).0 =
+            groups::aggregate_pks::<MinSig<H>, _, _>(&(self.0).0, pks, |pk| {
+                &pk.0
+            });
     }
 
     /// Verify a [`Signature`].
@@ -79,6 +88,31 @@ impl APK {
         self.0.verify(sig, msg)
     }
 
+    /// Verify a [`Signature`] over a message hashed with `H` instead of the
+    /// default [`LegacyBlake2b`].
+    /// Wrapper function for PublicKey.verify_with.
+    ///
+    /// [`LegacyBlake2b`]: crate::LegacyBlake2b
+    pub fn verify_with<H: HashToScalar>(
+        &self,
+        sig: &Signature,
+        msg: &[u8],
+    ) -> Result<(), Error> {
+        self.0.verify_with::<H>(sig, msg)
+    }
+
+    /// Verify a [`Signature`] over a message hashed by a [`MessageHasher`].
+    /// Wrapper function for PublicKey.verify_prehashed.
+    ///
+    /// [`MessageHasher`]: crate::MessageHasher
+    pub fn verify_prehashed(
+        &self,
+        sig: &Signature,
+        msg: &HashedMessage,
+    ) -> Result<(), Error> {
+        self.0.verify_prehashed(sig, msg)
+    }
+
     /// Raw bytes representation
     ///
     /// The intended usage of this function is for trusted sets of data where
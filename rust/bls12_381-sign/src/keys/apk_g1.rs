@@ -0,0 +1,90 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::groups::{self, MinPk};
+use crate::{Error, PublicKeyG1, SecretKey, SignatureG2};
+
+use dusk_bytes::{Error as DuskBytesError, Serializable};
+
+#[cfg(feature = "rkyv-impl")]
+use rkyv::{Archive, Deserialize, Serialize};
+
+/// Aggregated form of a BLS public key in G1.
+/// The public keys are aggregated in a rogue-key attack
+/// resistant manner, by using the hash function defined
+/// in the modified version of BLS.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv-impl",
+    derive(Archive, Deserialize, Serialize),
+    archive_attr(derive(bytecheck::CheckBytes))
+)]
+pub struct APKG1(PublicKeyG1);
+
+impl Serializable<48> for APKG1 {
+    type Error = DuskBytesError;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        Ok(APKG1(PublicKeyG1::from_bytes(bytes)?))
+    }
+}
+
+impl From<&PublicKeyG1> for APKG1 {
+    fn from(pk: &PublicKeyG1) -> Self {
+        Self(PublicKeyG1(pk.pk_t()))
+    }
+}
+
+impl From<&SecretKey> for APKG1 {
+    fn from(sk: &SecretKey) -> Self {
+        let pk = PublicKeyG1::from(sk);
+
+        Self::from(&pk)
+    }
+}
+
+impl APKG1 {
+    /// Aggregate a set of [`PublicKeyG1`] into the [`APKG1`].
+    pub fn aggregate(&mut self, pks: &[PublicKeyG1]) {
+        (self.0).0 =
+            groups::aggregate_pks::<MinPk, _, _>(&(self.0).0, pks, |pk| &pk.0);
+    }
+
+    /// Verify a [`SignatureG2`].
+    /// Wrapper function for PublicKeyG1.verify.
+    /// Currently, this function only supports batched signature verification
+    /// for the same message. Distinct messages are not supported.
+    pub fn verify(&self, sig: &SignatureG2, msg: &[u8]) -> Result<(), Error> {
+        self.0.verify(sig, msg)
+    }
+
+    /// Raw bytes representation
+    ///
+    /// The intended usage of this function is for trusted sets of data where
+    /// performance is critical.
+    ///
+    /// For secure serialization, check `to_bytes`
+    pub fn to_raw_bytes(&self) -> [u8; dusk_bls12_381::G1Affine::RAW_SIZE] {
+        self.0.to_raw_bytes()
+    }
+
+    /// Create a `APKG1` from a set of bytes created by `APKG1::to_raw_bytes`.
+    ///
+    /// # Safety
+    ///
+    /// No check is performed and no constant time is granted. The expected
+    /// usage of this function is for trusted bytes where performance is
+    /// critical.
+    ///
+    /// For secure serialization, check `from_bytes`
+    pub unsafe fn from_slice_unchecked(bytes: &[u8]) -> Self {
+        APKG1(PublicKeyG1::from_slice_unchecked(bytes))
+    }
+}
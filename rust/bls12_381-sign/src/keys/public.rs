@@ -4,8 +4,11 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use crate::hash::{h0, h1};
-use crate::{Error, SecretKey, Signature};
+use crate::groups::{self, MinSig};
+#[cfg(feature = "hash-to-curve")]
+use crate::hash::h0_aug;
+use crate::hash::HashToScalar;
+use crate::{Error, HashedMessage, SecretKey, Signature};
 
 use dusk_bls12_381::G2Affine;
 use dusk_bytes::{Error as DuskBytesError, Serializable};
@@ -41,10 +44,7 @@ impl From<&SecretKey> for PublicKey {
     /// Generates a new [`PublicKey`] from a [`SecretKey`].
     /// pk = g_2 * sk
     fn from(sk: &SecretKey) -> Self {
-        let g_2 = G2Affine::generator();
-        let gx = g_2 * sk.0;
-
-        Self(gx.into())
+        Self(groups::public_key::<MinSig>(&sk.0))
     }
 }
 
@@ -52,7 +52,46 @@ impl PublicKey {
     /// Verify a [`Signature`] by comparing the results of the two pairing
     /// operations: e(sig, g_2) == e(Hₒ(m), pk).
     pub fn verify(&self, sig: &Signature, msg: &[u8]) -> Result<(), Error> {
-        let h0m = h0(msg);
+        groups::verify::<MinSig>(&self.0, &sig.0, msg)
+    }
+
+    /// Verify a [`Signature`] over a message hashed with `H` instead of the
+    /// default [`LegacyBlake2b`]: e(sig, g_2) == e(Hₒ(m), pk).
+    ///
+    /// [`LegacyBlake2b`]: crate::LegacyBlake2b
+    pub fn verify_with<H: HashToScalar>(
+        &self,
+        sig: &Signature,
+        msg: &[u8],
+    ) -> Result<(), Error> {
+        groups::verify::<MinSig<H>>(&self.0, &sig.0, msg)
+    }
+
+    /// Verify a [`Signature`] over a message hashed by a [`MessageHasher`]:
+    /// e(sig, g_2) == e(h, pk).
+    ///
+    /// [`MessageHasher`]: crate::MessageHasher
+    pub fn verify_prehashed(
+        &self,
+        sig: &Signature,
+        msg: &HashedMessage,
+    ) -> Result<(), Error> {
+        groups::verify_hashed::<MinSig>(&self.0, &sig.0, &msg.0)
+    }
+
+    /// Verify a [`Signature`] produced with the message augmentation scheme,
+    /// by comparing the results of the two pairing operations:
+    /// e(sig, g_2) == e(Hₒ(pk || m), pk).
+    ///
+    /// The public key must be valid, since the identity would accept the
+    /// identity as signature for any message.
+    #[cfg(feature = "hash-to-curve")]
+    pub fn verify_aug(&self, sig: &Signature, msg: &[u8]) -> Result<(), Error> {
+        if !self.is_valid() {
+            return Err(Error::InvalidSignature);
+        }
+
+        let h0m = h0_aug(self, msg);
         let p1 = dusk_bls12_381::pairing(&sig.0, &G2Affine::generator());
         let p2 = dusk_bls12_381::pairing(&h0m, &self.0);
 
@@ -65,9 +104,12 @@ impl PublicKey {
 
     /// Return pk * t, where t is H_(pk).
     pub fn pk_t(&self) -> G2Affine {
-        let t = h1(self);
-        let gx = self.0 * t;
-        gx.into()
+        groups::pk_t::<MinSig>(&self.0)
+    }
+
+    /// Return pk * t, where t is H_(pk) computed with `H`.
+    pub fn pk_t_with<H: HashToScalar>(&self) -> G2Affine {
+        groups::pk_t::<MinSig<H>>(&self.0)
     }
 
     /// Raw bytes representation
@@ -0,0 +1,113 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::groups::{self, MinPk};
+use crate::{Error, SecretKey, SignatureG2};
+
+use dusk_bls12_381::G1Affine;
+use dusk_bytes::{Error as DuskBytesError, Serializable};
+
+#[cfg(feature = "rkyv-impl")]
+use rkyv::{Archive, Deserialize, Serialize};
+
+/// A BLS public key, holding a BLS12-381 G1 element inside.
+/// The G1 element is constructed by multiplying a [`SecretKey`]
+/// by `g1` (the base point of the G1 group).
+/// Can be used for verification of [`SignatureG2`] signatures.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv-impl",
+    derive(Archive, Deserialize, Serialize),
+    archive_attr(derive(bytecheck::CheckBytes))
+)]
+pub struct PublicKeyG1(pub(crate) G1Affine);
+
+impl Serializable<48> for PublicKeyG1 {
+    type Error = DuskBytesError;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        Ok(Self(G1Affine::from_bytes(bytes)?))
+    }
+}
+
+impl From<&SecretKey> for PublicKeyG1 {
+    /// Generates a new [`PublicKeyG1`] from a [`SecretKey`].
+    /// pk = g_1 * sk
+    fn from(sk: &SecretKey) -> Self {
+        Self(groups::public_key::<MinPk>(&sk.0))
+    }
+}
+
+impl PublicKeyG1 {
+    /// Verify a [`SignatureG2`] by comparing the results of the two pairing
+    /// operations: e(g_1, sig) == e(pk, Hₒ(m)).
+    pub fn verify(&self, sig: &SignatureG2, msg: &[u8]) -> Result<(), Error> {
+        groups::verify::<MinPk>(&self.0, &sig.0, msg)
+    }
+
+    /// Return pk * t, where t is H_(pk).
+    pub fn pk_t(&self) -> G1Affine {
+        groups::pk_t::<MinPk>(&self.0)
+    }
+
+    /// Raw bytes representation
+    ///
+    /// The intended usage of this function is for trusted sets of data where
+    /// performance is critical.
+    ///
+    /// For secure serialization, check `to_bytes`
+    pub fn to_raw_bytes(&self) -> [u8; G1Affine::RAW_SIZE] {
+        self.0.to_raw_bytes()
+    }
+
+    /// Create a `PublicKeyG1` from a set of bytes created by
+    /// `PublicKeyG1::to_raw_bytes`.
+    ///
+    /// # Safety
+    ///
+    /// No check is performed and no constant time is granted. The expected
+    /// usage of this function is for trusted bytes where performance is
+    /// critical.
+    ///
+    /// For secure serialization, check `from_bytes`
+    pub unsafe fn from_slice_unchecked(bytes: &[u8]) -> Self {
+        Self(G1Affine::from_slice_unchecked(bytes))
+    }
+
+    /// Returns true if the inner point is free of an $h$-torsion component, and
+    /// so it exists within the $q$-order subgroup $\mathbb{G}_1$. This
+    /// should always return true unless an "unchecked" API was used.
+    pub fn is_torsion_free(&self) -> bool {
+        self.0.is_torsion_free().into()
+    }
+
+    /// Returns true if the inner point is on the curve. This should always
+    /// return true unless an "unchecked" API was used.
+    pub fn is_on_curve(&self) -> bool {
+        self.0.is_on_curve().into()
+    }
+
+    /// Returns true if the inner point is the identity (the point at infinity).
+    pub fn is_identity(&self) -> bool {
+        self.0.is_identity().into()
+    }
+
+    /// Returns true if the inner point is valid according to certain criteria.
+    ///
+    /// A [`PublicKeyG1`] is considered valid if its inner point meets the
+    /// following conditions:
+    /// 1. It is free of an $h$-torsion component and exists within the
+    ///    $q$-order subgroup $\mathbb{G}_1$.
+    /// 2. It is on the curve.
+    /// 3. It is not the identity.
+    pub fn is_valid(&self) -> bool {
+        self.is_torsion_free() && self.is_on_curve() && !self.is_identity()
+    }
+}
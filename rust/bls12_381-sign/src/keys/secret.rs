@@ -4,8 +4,15 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use crate::hash::{h0, h1};
-use crate::{PublicKey, Signature};
+#[cfg(feature = "hash-to-curve")]
+use crate::groups::MinPk;
+use crate::groups::{self, MinSig};
+#[cfg(feature = "hash-to-curve")]
+use crate::hash::h0_aug;
+use crate::hash::HashToScalar;
+use crate::{HashedMessage, PublicKey, Signature};
+#[cfg(feature = "hash-to-curve")]
+use crate::{PublicKeyG1, SignatureG2};
 
 use dusk_bls12_381::BlsScalar;
 use dusk_bytes::{Error as DuskBytesError, Serializable};
@@ -73,22 +80,102 @@ impl SecretKey {
     /// Sign a message, producing a [`Signature`].
     /// The signature produced is vulnerable to a rogue-key attack.
     pub fn sign_vulnerable(&self, msg: &[u8]) -> Signature {
-        // Hash message
-        let h = h0(msg);
+        Signature(groups::sign_vulnerable::<MinSig>(&self.0, msg))
+    }
+
+    /// Sign a message in a rogue-key attack resistant way.
+    pub fn sign(&self, pk: &PublicKey, msg: &[u8]) -> Signature {
+        Signature(groups::sign::<MinSig>(&self.0, &pk.0, msg))
+    }
+
+    /// Sign a message, hashing it with `H` instead of the default
+    /// [`LegacyBlake2b`].
+    /// The signature produced is vulnerable to a rogue-key attack.
+    ///
+    /// [`LegacyBlake2b`]: crate::LegacyBlake2b
+    pub fn sign_vulnerable_with<H: HashToScalar>(
+        &self,
+        msg: &[u8],
+    ) -> Signature {
+        Signature(groups::sign_vulnerable::<MinSig<H>>(&self.0, msg))
+    }
+
+    /// Sign a message in a rogue-key attack resistant way, hashing both the
+    /// message and the public key with `H` instead of the default
+    /// [`LegacyBlake2b`].
+    ///
+    /// [`LegacyBlake2b`]: crate::LegacyBlake2b
+    pub fn sign_with<H: HashToScalar>(
+        &self,
+        pk: &PublicKey,
+        msg: &[u8],
+    ) -> Signature {
+        Signature(groups::sign::<MinSig<H>>(&self.0, &pk.0, msg))
+    }
+
+    /// Sign a message hashed by a [`MessageHasher`] in a rogue-key attack
+    /// resistant way, producing the same [`Signature`] as `sign` over the
+    /// whole message.
+    ///
+    /// [`MessageHasher`]: crate::MessageHasher
+    pub fn sign_prehashed(
+        &self,
+        pk: &PublicKey,
+        msg: &HashedMessage,
+    ) -> Signature {
+        Signature(groups::sign_prehashed::<MinSig>(&self.0, &pk.0, &msg.0))
+    }
+
+    /// Sign a message hashed by a [`MessageHasher<H>`] in a rogue-key attack
+    /// resistant way, hashing the public key with `H`. Produces the same
+    /// [`Signature`] as `sign_with::<H>` over the whole message.
+    ///
+    /// [`MessageHasher<H>`]: crate::MessageHasher
+    pub fn sign_prehashed_with<H: HashToScalar>(
+        &self,
+        pk: &PublicKey,
+        msg: &HashedMessage,
+    ) -> Signature {
+        Signature(groups::sign_prehashed::<MinSig<H>>(&self.0, &pk.0, &msg.0))
+    }
+
+    /// Sign a message using the message augmentation scheme, producing a
+    /// [`Signature`].
+    ///
+    /// The signer's [`PublicKey`] is prepended to the message before hashing,
+    /// which makes the signatures safe to aggregate over distinct messages
+    /// without the modified construction or proofs of possession. Signatures
+    /// are those of the `BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_AUG_`
+    /// ciphersuite of the IETF BLS signature draft.
+    #[cfg(feature = "hash-to-curve")]
+    pub fn sign_aug(&self, pk: &PublicKey, msg: &[u8]) -> Signature {
+        let h = h0_aug(pk, msg);
 
-        // Multiply point by sk
         let e = h * self.0;
         Signature(e.into())
     }
 
-    /// Sign a message in a rogue-key attack resistant way.
-    pub fn sign(&self, pk: &PublicKey, msg: &[u8]) -> Signature {
-        let mut sig = self.sign_vulnerable(msg);
+    /// Sign a message, producing a [`SignatureG2`] to be verified with a
+    /// [`PublicKeyG1`].
+    /// The signature produced is vulnerable to a rogue-key attack.
+    ///
+    /// Messages are hashed into G2 with the hash-to-curve of the
+    /// `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_` ciphersuite. Its
+    /// [`PublicKeyG1`] is the key times the generator of G1, from which the
+    /// signatures in G1 over messages hashed with a known discrete logarithm,
+    /// as with `sign`, can be forged: a key signing in both groups should
+    /// only sign in G1 with the `hash-to-curve` schemes, such as `sign_aug`.
+    #[cfg(feature = "hash-to-curve")]
+    pub fn sign_vulnerable_g2(&self, msg: &[u8]) -> SignatureG2 {
+        SignatureG2(groups::sign_vulnerable::<MinPk>(&self.0, msg))
+    }
 
-        // Turn signature into its modified construction,
-        // which provides protection against rogue-key attacks.
-        let t = h1(pk);
-        sig.0 = (sig.0 * t).into();
-        sig
+    /// Sign a message in a rogue-key attack resistant way, producing a
+    /// [`SignatureG2`] to be verified with an [`APKG1`].
+    ///
+    /// [`APKG1`]: crate::APKG1
+    #[cfg(feature = "hash-to-curve")]
+    pub fn sign_g2(&self, pk: &PublicKeyG1, msg: &[u8]) -> SignatureG2 {
+        SignatureG2(groups::sign::<MinPk>(&self.0, &pk.0, msg))
     }
 }
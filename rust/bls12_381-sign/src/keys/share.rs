@@ -0,0 +1,174 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::shamir;
+use crate::{Error, PublicKey, SecretKey};
+
+use alloc::vec::Vec;
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::{Error as DuskBytesError, Serializable};
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "rkyv-impl")]
+use rkyv::{Archive, Deserialize, Serialize};
+
+const CHECKSUM_SIZE: usize = 4;
+
+/// A share of a [`SecretKey`].
+///
+/// A [`SecretKey`] split with [`SecretKey::split`] into `n` shares can be
+/// recovered from any `threshold` of them with [`SecretKey::recover`], or
+/// used without recovery to produce threshold signatures with
+/// `KeyShare::sign_partial`.
+///
+/// The serialized form holds the share index, the threshold, the share value
+/// and a checksum over the former, so that corrupted or mistyped shares are
+/// rejected on deserialization.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv-impl",
+    derive(Archive, Deserialize, Serialize),
+    archive_attr(derive(bytecheck::CheckBytes))
+)]
+pub struct KeyShare {
+    pub(crate) index: u8,
+    pub(crate) threshold: u8,
+    pub(crate) value: BlsScalar,
+}
+
+impl KeyShare {
+    /// Index of the share, starting from 1.
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Number of shares needed to recover the [`SecretKey`].
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    fn checksum(bytes: &[u8]) -> [u8; CHECKSUM_SIZE] {
+        let digest = Blake2b::<U32>::digest(bytes);
+
+        let mut checksum = [0u8; CHECKSUM_SIZE];
+        checksum.copy_from_slice(&digest[..CHECKSUM_SIZE]);
+        checksum
+    }
+}
+
+impl Serializable<38> for KeyShare {
+    type Error = DuskBytesError;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        let (body, checksum) = bytes.split_at_mut(Self::SIZE - CHECKSUM_SIZE);
+
+        body[0] = self.index;
+        body[1] = self.threshold;
+        body[2..].copy_from_slice(&self.value.to_bytes());
+        checksum.copy_from_slice(&Self::checksum(body));
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        let (body, checksum) = bytes.split_at(Self::SIZE - CHECKSUM_SIZE);
+        if checksum != Self::checksum(body) {
+            return Err(DuskBytesError::InvalidData);
+        }
+
+        let index = body[0];
+        let threshold = body[1];
+        if index == 0 || threshold == 0 {
+            return Err(DuskBytesError::InvalidData);
+        }
+
+        let mut value = [0u8; BlsScalar::SIZE];
+        value.copy_from_slice(&body[2..]);
+        let value = match BlsScalar::from_bytes(&value).into() {
+            Some(value) => value,
+            None => return Err(DuskBytesError::InvalidData),
+        };
+
+        Ok(Self {
+            index,
+            threshold,
+            value,
+        })
+    }
+}
+
+impl SecretKey {
+    /// Split the [`SecretKey`] into `n` backup [`KeyShare`]s, any `threshold`
+    /// of which are enough to recover it.
+    ///
+    /// Fails with [`Error::InvalidThreshold`] unless `0 < threshold <= n`.
+    pub fn split<R>(
+        &self,
+        rng: &mut R,
+        threshold: u8,
+        n: u8,
+    ) -> Result<Vec<KeyShare>, Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        if threshold == 0 || threshold > n {
+            return Err(Error::InvalidThreshold);
+        }
+
+        Ok(shamir::split(rng, &self.0, threshold, n)
+            .into_iter()
+            .map(|(index, value)| KeyShare {
+                index,
+                threshold,
+                value,
+            })
+            .collect())
+    }
+
+    /// Recover a [`SecretKey`] from its backup [`KeyShare`]s, and confirm it
+    /// against the stored [`PublicKey`].
+    ///
+    /// Fails with [`Error::InvalidShares`] if fewer shares than their
+    /// threshold are given, if they disagree on the threshold, if an index is
+    /// repeated, or if the recovered key doesn't match `pk`.
+    pub fn recover(
+        shares: &[KeyShare],
+        pk: &PublicKey,
+    ) -> Result<SecretKey, Error> {
+        let threshold = match shares.first() {
+            Some(share) => share.threshold,
+            None => return Err(Error::InvalidShares),
+        };
+        if shares.len() < threshold as usize
+            || shares.iter().any(|share| share.threshold != threshold)
+        {
+            return Err(Error::InvalidShares);
+        }
+
+        let shares = &shares[..threshold as usize];
+        let indexes: Vec<u8> = shares.iter().map(|share| share.index).collect();
+        let coefficients = shamir::lagrange_coefficients(&indexes)
+            .ok_or(Error::InvalidShares)?;
+
+        let sk = SecretKey(
+            shares
+                .iter()
+                .zip(coefficients.iter())
+                .map(|(share, l)| share.value * l)
+                .sum(),
+        );
+
+        if PublicKey::from(&sk) == *pk {
+            Ok(sk)
+        } else {
+            Err(Error::InvalidShares)
+        }
+    }
+}
@@ -9,21 +9,112 @@
 //! Implementation of BLS signatures on the BLS12-381 curve.
 //! Reference paper: https://crypto.stanford.edu/~dabo/pubs/papers/BLSmultisig.html
 
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod batch;
+#[cfg(feature = "hash-to-curve")]
+mod beacon;
+#[cfg(feature = "hash-to-curve")]
+mod blind;
+mod envelope;
 mod error;
+mod groups;
 mod hash;
+#[cfg(feature = "hash-to-curve")]
+mod ibe;
 mod keys;
+#[cfg(feature = "hash-to-curve")]
+mod rotation;
+mod shamir;
 mod signature;
+#[cfg(feature = "hash-to-curve")]
+mod signature_g2;
+mod slashing;
+mod structured;
+mod threshold;
+#[cfg(feature = "hash-to-curve")]
+mod vrf;
+#[cfg(feature = "zk")]
+pub mod zk;
 
+pub use batch::{find_invalid_signers, verify_batch};
+#[cfg(feature = "hash-to-curve")]
+pub use beacon::{Beacon, BeaconMode};
+#[cfg(feature = "hash-to-curve")]
+pub use blind::{BlindSignature, BlindedMessage, BlindingFactor};
+pub use envelope::{MemoryNonceStore, NonceStore, SignedEnvelope};
 pub use error::Error;
-pub use keys::{apk::APK, public::PublicKey, secret::SecretKey};
+#[cfg(feature = "blake3")]
+pub use hash::Blake3Wide;
+#[cfg(feature = "poseidon")]
+pub use hash::Poseidon;
+#[cfg(feature = "sha2")]
+pub use hash::Sha256Wide;
+pub use hash::{
+    Blake2bWide, HashToScalar, HashedMessage, LegacyBlake2b, MessageHasher,
+    WideDigest,
+};
+#[cfg(feature = "hash-to-curve")]
+pub use ibe::Ciphertext;
+pub use keys::{
+    apk::APK, public::PublicKey, secret::SecretKey, share::KeyShare,
+};
+#[cfg(feature = "hash-to-curve")]
+pub use keys::{apk_g1::APKG1, public_g1::PublicKeyG1};
+#[cfg(feature = "hash-to-curve")]
+pub use rotation::KeyRotation;
 pub use signature::Signature;
+#[cfg(feature = "hash-to-curve")]
+pub use signature_g2::SignatureG2;
+#[cfg(feature = "std")]
+pub use slashing::FileSlashingStore;
+#[cfg(feature = "interchange")]
+pub use slashing::{export_interchange, import_interchange};
+pub use slashing::{
+    MemorySlashingStore, SigningRecord, SlashingProtectedKey, SlashingStore,
+};
+pub use structured::Signable;
+pub use threshold::PartialSignature;
+#[cfg(feature = "hash-to-curve")]
+pub use vrf::{VrfOutput, VrfProof};
+
+#[cfg(all(feature = "rkyv-impl", feature = "hash-to-curve"))]
+pub use crate::blind::{
+    ArchivedBlindSignature, ArchivedBlindedMessage, ArchivedBlindingFactor,
+    BlindSignatureResolver, BlindedMessageResolver, BlindingFactorResolver,
+};
 
 #[cfg(feature = "rkyv-impl")]
 pub use crate::keys::{
     apk::{APKResolver, ArchivedAPK},
     public::{ArchivedPublicKey, PublicKeyResolver},
     secret::{ArchivedSecretKey, SecretKeyResolver},
+    share::{ArchivedKeyShare, KeyShareResolver},
 };
 
+#[cfg(all(feature = "rkyv-impl", feature = "hash-to-curve"))]
+pub use crate::rotation::{ArchivedKeyRotation, KeyRotationResolver};
+
 #[cfg(feature = "rkyv-impl")]
 pub use crate::signature::{ArchivedSignature, SignatureResolver};
+
+#[cfg(all(feature = "rkyv-impl", feature = "hash-to-curve"))]
+pub use crate::keys::{
+    apk_g1::{APKG1Resolver, ArchivedAPKG1},
+    public_g1::{ArchivedPublicKeyG1, PublicKeyG1Resolver},
+};
+
+#[cfg(all(feature = "rkyv-impl", feature = "hash-to-curve"))]
+pub use crate::signature_g2::{ArchivedSignatureG2, SignatureG2Resolver};
+
+#[cfg(feature = "rkyv-impl")]
+pub use crate::threshold::{
+    ArchivedPartialSignature, PartialSignatureResolver,
+};
+
+#[cfg(all(feature = "rkyv-impl", feature = "hash-to-curve"))]
+pub use crate::vrf::{
+    ArchivedVrfOutput, ArchivedVrfProof, VrfOutputResolver, VrfProofResolver,
+};
@@ -0,0 +1,146 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Certificates of the rotation of a signer's key.
+//!
+//! The old key endorses the new one, and the new key proves possession of its
+//! secret, both over the pair of public keys and under distinct domain
+//! separation tags, so neither signature can be reused in another context.
+//!
+//! The pair is hashed to the curve with `h0_htc`, since the discrete logarithm
+//! of the points of `h0` is public and any signature of the old key would
+//! give away its endorsement of any new one.
+
+use crate::groups::{self, MinSig};
+use crate::hash::h0_htc;
+use crate::{Error, PublicKey, SecretKey, Signature};
+
+use dusk_bls12_381::G1Affine;
+use dusk_bytes::{DeserializableSlice, Serializable};
+
+#[cfg(feature = "rkyv-impl")]
+use rkyv::{Archive, Deserialize, Serialize};
+
+/// Domain separation tag of the endorsement by the old key.
+const ENDORSEMENT_DST: &[u8] =
+    b"DUSK_BLS12381G1_XMD:SHA-256_SSWU_RO_ROTATION_ENDORSEMENT_";
+/// Domain separation tag of the proof of possession of the new key.
+const POSSESSION_DST: &[u8] =
+    b"DUSK_BLS12381G1_XMD:SHA-256_SSWU_RO_ROTATION_POSSESSION_";
+
+/// A record of the rotation from an old [`PublicKey`] to a new one, signed
+/// by both the old and the new [`SecretKey`].
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv-impl",
+    derive(Archive, Deserialize, Serialize),
+    archive_attr(derive(bytecheck::CheckBytes))
+)]
+pub struct KeyRotation {
+    old_pk: PublicKey,
+    new_pk: PublicKey,
+    endorsement: Signature,
+    possession: Signature,
+}
+
+impl KeyRotation {
+    /// Create the record of the rotation from `old_sk` to `new_sk`.
+    pub fn new(old_sk: &SecretKey, new_sk: &SecretKey) -> Self {
+        let old_pk = PublicKey::from(old_sk);
+        let new_pk = PublicKey::from(new_sk);
+
+        let endorsement = hash(ENDORSEMENT_DST, &old_pk, &new_pk);
+        let possession = hash(POSSESSION_DST, &old_pk, &new_pk);
+
+        Self {
+            old_pk,
+            new_pk,
+            endorsement: Signature(groups::sign_hashed::<MinSig>(
+                &old_sk.0,
+                &endorsement,
+            )),
+            possession: Signature(groups::sign_hashed::<MinSig>(
+                &new_sk.0,
+                &possession,
+            )),
+        }
+    }
+
+    /// The [`PublicKey`] being rotated out.
+    pub fn old_public_key(&self) -> &PublicKey {
+        &self.old_pk
+    }
+
+    /// The [`PublicKey`] being rotated in.
+    pub fn new_public_key(&self) -> &PublicKey {
+        &self.new_pk
+    }
+
+    /// Verify that the rotation was signed by both the old and the new key.
+    ///
+    /// Both public keys must be valid, since the identity would accept the
+    /// identity as signature.
+    pub fn verify(&self) -> Result<(), Error> {
+        if !self.old_pk.is_valid() || !self.new_pk.is_valid() {
+            return Err(Error::InvalidSignature);
+        }
+
+        let endorsement = hash(ENDORSEMENT_DST, &self.old_pk, &self.new_pk);
+        let possession = hash(POSSESSION_DST, &self.old_pk, &self.new_pk);
+
+        groups::verify_hashed::<MinSig>(
+            &self.old_pk.0,
+            &self.endorsement.0,
+            &endorsement,
+        )?;
+        groups::verify_hashed::<MinSig>(
+            &self.new_pk.0,
+            &self.possession.0,
+            &possession,
+        )
+    }
+}
+
+/// Hash the pair of public keys into G1 under the domain separation tag.
+fn hash(dst: &[u8], old_pk: &PublicKey, new_pk: &PublicKey) -> G1Affine {
+    let mut msg = [0u8; 2 * PublicKey::SIZE];
+    msg[..PublicKey::SIZE].copy_from_slice(&old_pk.to_bytes());
+    msg[PublicKey::SIZE..].copy_from_slice(&new_pk.to_bytes());
+
+    h0_htc(dst, &msg)
+}
+
+impl Serializable<288> for KeyRotation {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+
+        bytes[..96].copy_from_slice(&self.old_pk.to_bytes());
+        bytes[96..192].copy_from_slice(&self.new_pk.to_bytes());
+        bytes[192..240].copy_from_slice(&self.endorsement.to_bytes());
+        bytes[240..].copy_from_slice(&self.possession.to_bytes());
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        Ok(Self {
+            old_pk: PublicKey::from_slice(&bytes[..96])?,
+            new_pk: PublicKey::from_slice(&bytes[96..192])?,
+            endorsement: signature(&bytes[192..240])?,
+            possession: signature(&bytes[240..])?,
+        })
+    }
+}
+
+/// Deserialize a [`Signature`] from a slice of `Signature::SIZE` bytes.
+fn signature(bytes: &[u8]) -> Result<Signature, Error> {
+    let mut sig = [0u8; Signature::SIZE];
+    sig.copy_from_slice(bytes);
+
+    Signature::from_bytes(&sig)
+}
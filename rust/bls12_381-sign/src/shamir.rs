@@ -0,0 +1,72 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Shamir secret sharing over the BLS12-381 scalar field.
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+use ff::Field;
+use rand_core::{CryptoRng, RngCore};
+
+/// Split `secret` into `n` shares, any `threshold` of which allow to recover
+/// it, by evaluating a random polynomial of degree `threshold - 1` with
+/// constant term `secret` at the indexes `1..=n`.
+///
+/// The caller is responsible for checking that `0 < threshold <= n`.
+pub(crate) fn split<R>(
+    rng: &mut R,
+    secret: &BlsScalar,
+    threshold: u8,
+    n: u8,
+) -> Vec<(u8, BlsScalar)>
+where
+    R: RngCore + CryptoRng,
+{
+    let mut coefficients = Vec::with_capacity(threshold as usize);
+    coefficients.push(*secret);
+    for _ in 1..threshold {
+        coefficients.push(BlsScalar::random(&mut *rng));
+    }
+
+    (1..=n)
+        .map(|index| {
+            // Horner's method
+            let x = BlsScalar::from(index as u64);
+            let y = coefficients
+                .iter()
+                .rev()
+                .fold(BlsScalar::zero(), |acc, c| acc * x + c);
+            (index, y)
+        })
+        .collect()
+}
+
+/// Compute the Lagrange coefficients at zero for the given share indexes.
+///
+/// Returns `None` if any index is zero or appears more than once.
+pub(crate) fn lagrange_coefficients(indexes: &[u8]) -> Option<Vec<BlsScalar>> {
+    for (i, index) in indexes.iter().enumerate() {
+        if *index == 0 || indexes[..i].contains(index) {
+            return None;
+        }
+    }
+
+    indexes
+        .iter()
+        .map(|&i| {
+            let xi = BlsScalar::from(i as u64);
+            let (num, den) = indexes.iter().filter(|&&j| j != i).fold(
+                (BlsScalar::one(), BlsScalar::one()),
+                |(num, den), &j| {
+                    let xj = BlsScalar::from(j as u64);
+                    (num * xj, den * (xj - xi))
+                },
+            );
+            den.invert().map(|inv| num * inv)
+        })
+        .collect()
+}
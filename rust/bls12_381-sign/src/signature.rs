@@ -4,9 +4,19 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+use crate::groups::{self, MinSig};
+#[cfg(feature = "hash-to-curve")]
+use crate::hash::h0_aug;
 use crate::Error;
+#[cfg(feature = "hash-to-curve")]
+use crate::PublicKey;
 
-use dusk_bls12_381::{G1Affine, G1Projective};
+#[cfg(feature = "hash-to-curve")]
+use alloc::vec::Vec;
+
+use dusk_bls12_381::G1Affine;
+#[cfg(feature = "hash-to-curve")]
+use dusk_bls12_381::{multi_miller_loop, G2Affine, G2Prepared, Gt};
 use dusk_bytes::Serializable;
 
 #[cfg(feature = "rkyv-impl")]
@@ -24,11 +34,44 @@ pub struct Signature(pub(crate) G1Affine);
 impl Signature {
     /// Aggregate a set of signatures by simply adding up the points.
     pub fn aggregate(&self, sigs: &[Signature]) -> Self {
-        Self(
-            sigs.iter().fold(self.0, |acc, sig| {
-                (acc + G1Projective::from(sig.0)).into()
-            }),
-        )
+        Self(groups::aggregate_sigs::<MinSig, _, _>(
+            &self.0,
+            sigs,
+            |sig| &sig.0,
+        ))
+    }
+
+    /// Verify an aggregate of [`Signature`]s produced with the message
+    /// augmentation scheme, each by a different signer over a possibly
+    /// different message, by checking:
+    /// e(sig, g_2) == ∏ e(Hₒ(pk_i || m_i), pk_i).
+    ///
+    /// An empty set of signers is always invalid, and so is a set with an
+    /// invalid public key.
+    #[cfg(feature = "hash-to-curve")]
+    pub fn verify_aggregate_aug(
+        &self,
+        signers: &[(PublicKey, &[u8])],
+    ) -> Result<(), Error> {
+        if signers.is_empty() || signers.iter().any(|(pk, _)| !pk.is_valid()) {
+            return Err(Error::InvalidSignature);
+        }
+
+        let terms: Vec<(G1Affine, G2Prepared)> = signers
+            .iter()
+            .map(|(pk, msg)| (-h0_aug(pk, msg), G2Prepared::from(pk.0)))
+            .collect();
+
+        let g2 = G2Prepared::from(G2Affine::generator());
+        let mut refs = Vec::with_capacity(terms.len() + 1);
+        refs.push((&self.0, &g2));
+        refs.extend(terms.iter().map(|(h, pk)| (h, pk)));
+
+        if multi_miller_loop(&refs).final_exponentiation() == Gt::identity() {
+            Ok(())
+        } else {
+            Err(Error::InvalidSignature)
+        }
     }
 }
 
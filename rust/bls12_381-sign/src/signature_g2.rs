@@ -0,0 +1,48 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::groups::{self, MinPk};
+use crate::Error;
+
+use dusk_bls12_381::G2Affine;
+use dusk_bytes::Serializable;
+
+#[cfg(feature = "rkyv-impl")]
+use rkyv::{Archive, Deserialize, Serialize};
+
+/// A BLS signature in G2, verified with a [`PublicKeyG1`].
+///
+/// [`PublicKeyG1`]: crate::PublicKeyG1
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv-impl",
+    derive(Archive, Deserialize, Serialize),
+    archive_attr(derive(bytecheck::CheckBytes))
+)]
+pub struct SignatureG2(pub(crate) G2Affine);
+
+impl SignatureG2 {
+    /// Aggregate a set of signatures by simply adding up the points.
+    pub fn aggregate(&self, sigs: &[SignatureG2]) -> Self {
+        Self(groups::aggregate_sigs::<MinPk, _, _>(
+            &self.0,
+            sigs,
+            |sig| &sig.0,
+        ))
+    }
+}
+
+impl Serializable<96> for SignatureG2 {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        Ok(Self(G2Affine::from_bytes(bytes)?))
+    }
+}
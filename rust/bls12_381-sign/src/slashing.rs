@@ -0,0 +1,543 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Protection against signing two conflicting messages for the same round
+//! and step of a consensus protocol.
+//!
+//! A [`SlashingProtectedKey`] records the hash of every message it signs in a
+//! [`SlashingStore`], and refuses to sign a different message for a round and
+//! step it already signed. With the `std` feature, the records can be
+//! persisted to a file, and with the `interchange` feature exchanged in a
+//! JSON format modeled after [EIP-3076](https://eips.ethereum.org/EIPS/eip-3076).
+
+use crate::{Error, PublicKey, SecretKey, Signature};
+
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use dusk_bytes::Serializable;
+
+/// Domain separation tag of the message hashes.
+const MESSAGE_DST: &[u8] = b"DUSK_BLS12381_SLASHING_MESSAGE";
+
+/// The record of a message signed for a round and step.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SigningRecord {
+    /// The round the message was signed for.
+    pub round: u64,
+    /// The step of the round the message was signed for.
+    pub step: u8,
+    /// The hash of the signed message.
+    pub msg_hash: [u8; 32],
+}
+
+impl SigningRecord {
+    /// Create the record of `msg` signed for `round` and `step`.
+    pub fn new(round: u64, step: u8, msg: &[u8]) -> Self {
+        let msg_hash = Blake2b::<U32>::new()
+            .chain_update(MESSAGE_DST)
+            .chain_update(msg)
+            .finalize()
+            .into();
+
+        Self {
+            round,
+            step,
+            msg_hash,
+        }
+    }
+}
+
+/// Storage of the [`SigningRecord`]s of a set of signers.
+pub trait SlashingStore {
+    /// The hash of the message signed by `pk` for `round` and `step`, if any.
+    fn get(
+        &self,
+        pk: &PublicKey,
+        round: u64,
+        step: u8,
+    ) -> Result<Option<[u8; 32]>, Error>;
+
+    /// Record a message signed by `pk`. The record must be persisted by the
+    /// time this returns.
+    fn insert(
+        &mut self,
+        pk: &PublicKey,
+        record: &SigningRecord,
+    ) -> Result<(), Error>;
+
+    /// All the records of the store, ordered by signer, round and step.
+    fn records(&self) -> Result<Vec<(PublicKey, SigningRecord)>, Error>;
+
+    /// Check that signing `record` with `pk` doesn't conflict with a message
+    /// already signed, and record it.
+    ///
+    /// Signing the same message again for a round and step is allowed.
+    /// Fails with [`Error::DoubleSign`] if a different message was signed.
+    fn check_and_insert(
+        &mut self,
+        pk: &PublicKey,
+        record: &SigningRecord,
+    ) -> Result<(), Error> {
+        match self.get(pk, record.round, record.step)? {
+            Some(hash) if hash == record.msg_hash => Ok(()),
+            Some(_) => Err(Error::DoubleSign),
+            None => self.insert(pk, record),
+        }
+    }
+}
+
+/// Signer, round and step of a record.
+type RecordKey = ([u8; PublicKey::SIZE], u64, u8);
+
+/// A [`SlashingStore`] holding the records in memory.
+#[derive(Default, Clone, Debug)]
+pub struct MemorySlashingStore {
+    records: BTreeMap<RecordKey, (PublicKey, [u8; 32])>,
+}
+
+impl MemorySlashingStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SlashingStore for MemorySlashingStore {
+    fn get(
+        &self,
+        pk: &PublicKey,
+        round: u64,
+        step: u8,
+    ) -> Result<Option<[u8; 32]>, Error> {
+        Ok(self
+            .records
+            .get(&(pk.to_bytes(), round, step))
+            .map(|(_, hash)| *hash))
+    }
+
+    fn insert(
+        &mut self,
+        pk: &PublicKey,
+        record: &SigningRecord,
+    ) -> Result<(), Error> {
+        self.records.insert(
+            (pk.to_bytes(), record.round, record.step),
+            (*pk, record.msg_hash),
+        );
+        Ok(())
+    }
+
+    fn records(&self) -> Result<Vec<(PublicKey, SigningRecord)>, Error> {
+        Ok(self
+            .records
+            .iter()
+            .map(|((_, round, step), (pk, msg_hash))| {
+                let record = SigningRecord {
+                    round: *round,
+                    step: *step,
+                    msg_hash: *msg_hash,
+                };
+                (*pk, record)
+            })
+            .collect())
+    }
+}
+
+/// A [`SecretKey`] refusing to sign conflicting messages for the same round
+/// and step, as recorded in a [`SlashingStore`].
+#[derive(Debug)]
+pub struct SlashingProtectedKey<S> {
+    sk: SecretKey,
+    pk: PublicKey,
+    store: S,
+}
+
+impl<S: SlashingStore> SlashingProtectedKey<S> {
+    /// Protect `sk` with the records of `store`.
+    pub fn new(sk: SecretKey, store: S) -> Self {
+        let pk = PublicKey::from(&sk);
+        Self { sk, pk, store }
+    }
+
+    /// The [`PublicKey`] of the protected key.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.pk
+    }
+
+    /// The store of the records.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Consume the protected key, returning the store of the records.
+    pub fn into_store(self) -> S {
+        self.store
+    }
+
+    /// Sign `msg` for `round` and `step` in a rogue-key attack resistant way,
+    /// as `SecretKey::sign`.
+    ///
+    /// Fails with [`Error::DoubleSign`] if a different message was already
+    /// signed for the same round and step. The message is recorded before
+    /// the [`Signature`] is returned.
+    pub fn sign(
+        &mut self,
+        round: u64,
+        step: u8,
+        msg: &[u8],
+    ) -> Result<Signature, Error> {
+        let record = SigningRecord::new(round, step, msg);
+        self.store.check_and_insert(&self.pk, &record)?;
+
+        Ok(self.sk.sign(&self.pk, msg))
+    }
+}
+
+#[cfg(feature = "interchange")]
+pub use interchange::{export_interchange, import_interchange};
+
+#[cfg(feature = "std")]
+pub use file::FileSlashingStore;
+
+#[cfg(feature = "std")]
+fn to_hex(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let mut hex = String::with_capacity(2 + 2 * bytes.len());
+    hex.push_str("0x");
+    for byte in bytes {
+        hex.push(DIGITS[(byte >> 4) as usize] as char);
+        hex.push(DIGITS[(byte & 0xf) as usize] as char);
+    }
+
+    hex
+}
+
+#[cfg(feature = "std")]
+fn from_hex<const N: usize>(hex: &str) -> Result<[u8; N], Error> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex).as_bytes();
+    if hex.len() != 2 * N {
+        return Err(Error::InvalidInterchange);
+    }
+
+    let digit = |c: u8| {
+        (c as char)
+            .to_digit(16)
+            .map(|d| d as u8)
+            .ok_or(Error::InvalidInterchange)
+    };
+
+    let mut bytes = [0u8; N];
+    for (byte, pair) in bytes.iter_mut().zip(hex.chunks(2)) {
+        *byte = digit(pair[0])? << 4 | digit(pair[1])?;
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(feature = "interchange")]
+mod interchange {
+    use super::{from_hex, to_hex, RecordKey, SigningRecord, SlashingStore};
+    use crate::{Error, PublicKey};
+
+    use std::collections::BTreeMap;
+    use std::string::{String, ToString};
+    use std::vec::Vec;
+
+    use dusk_bytes::Serializable;
+    use serde::{Deserialize, Serialize};
+
+    /// Version of the interchange format.
+    const FORMAT_VERSION: &str = "5";
+
+    #[derive(Serialize, Deserialize)]
+    struct Interchange {
+        metadata: Metadata,
+        data: Vec<SignerData>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Metadata {
+        interchange_format_version: String,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct SignerData {
+        pubkey: String,
+        signed_votes: Vec<SignedVote>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct SignedVote {
+        round: String,
+        step: String,
+        message_hash: String,
+    }
+
+    /// Export the records of `store` in the interchange format.
+    pub fn export_interchange<S: SlashingStore>(
+        store: &S,
+    ) -> Result<String, Error> {
+        let mut data: Vec<SignerData> = Vec::new();
+
+        for (pk, record) in store.records()? {
+            let pubkey = to_hex(&pk.to_bytes());
+            let vote = SignedVote {
+                round: record.round.to_string(),
+                step: record.step.to_string(),
+                message_hash: to_hex(&record.msg_hash),
+            };
+
+            // The records are ordered by signer.
+            match data.last_mut() {
+                Some(signer) if signer.pubkey == pubkey => {
+                    signer.signed_votes.push(vote)
+                }
+                _ => data.push(SignerData {
+                    pubkey,
+                    signed_votes: Vec::from([vote]),
+                }),
+            }
+        }
+
+        let interchange = Interchange {
+            metadata: Metadata {
+                interchange_format_version: FORMAT_VERSION.into(),
+            },
+            data,
+        };
+
+        serde_json::to_string_pretty(&interchange)
+            .map_err(|_| Error::InvalidInterchange)
+    }
+
+    /// Import records in the interchange format into `store`.
+    ///
+    /// Fails with [`Error::DoubleSign`] if a record conflicts with another
+    /// record of the interchange or with one already in the store, in which
+    /// case no record is imported.
+    pub fn import_interchange<S: SlashingStore>(
+        store: &mut S,
+        json: &str,
+    ) -> Result<(), Error> {
+        let records = parse(json)?;
+
+        let mut imported: BTreeMap<RecordKey, [u8; 32]> = BTreeMap::new();
+        for (pk, record) in &records {
+            let key = (pk.to_bytes(), record.round, record.step);
+            if let Some(hash) = imported.insert(key, record.msg_hash) {
+                if hash != record.msg_hash {
+                    return Err(Error::DoubleSign);
+                }
+            }
+
+            if let Some(hash) = store.get(pk, record.round, record.step)? {
+                if hash != record.msg_hash {
+                    return Err(Error::DoubleSign);
+                }
+            }
+        }
+
+        records
+            .iter()
+            .try_for_each(|(pk, record)| store.check_and_insert(pk, record))
+    }
+
+    fn parse(json: &str) -> Result<Vec<(PublicKey, SigningRecord)>, Error> {
+        let interchange: Interchange = serde_json::from_str(json)
+            .map_err(|_| Error::InvalidInterchange)?;
+
+        if interchange.metadata.interchange_format_version != FORMAT_VERSION {
+            return Err(Error::InvalidInterchange);
+        }
+
+        let mut records = Vec::new();
+        for signer in interchange.data {
+            let pk = PublicKey::from_bytes(&from_hex(&signer.pubkey)?)
+                .map_err(|_| Error::InvalidInterchange)?;
+
+            for vote in signer.signed_votes {
+                let record = SigningRecord {
+                    round: vote
+                        .round
+                        .parse()
+                        .map_err(|_| Error::InvalidInterchange)?,
+                    step: vote
+                        .step
+                        .parse()
+                        .map_err(|_| Error::InvalidInterchange)?,
+                    msg_hash: from_hex(&vote.message_hash)?,
+                };
+                records.push((pk, record));
+            }
+        }
+
+        Ok(records)
+    }
+}
+
+#[cfg(feature = "std")]
+mod file {
+    use super::{
+        from_hex, to_hex, MemorySlashingStore, SigningRecord, SlashingStore,
+    };
+    use crate::{Error, PublicKey};
+
+    use std::format;
+    use std::fs::{self, File, OpenOptions};
+    use std::io::{ErrorKind, Write};
+    use std::path::Path;
+    use std::vec::Vec;
+
+    use dusk_bytes::Serializable;
+
+    /// A [`SlashingStore`] persisting the records to an append-only file.
+    ///
+    /// Every insertion appends a line with the public key, round, step and
+    /// message hash of the record to the file, and syncs it to disk before
+    /// returning. A line left incomplete by a crash belongs to a record that
+    /// was never confirmed, and is discarded when the store is opened.
+    #[derive(Debug)]
+    pub struct FileSlashingStore {
+        file: File,
+        /// Length of the complete records of the file
+        len: u64,
+        memory: MemorySlashingStore,
+    }
+
+    impl FileSlashingStore {
+        /// Open the store at `path`, loading its records if the file exists.
+        pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+            let path = path.as_ref();
+            let mut memory = MemorySlashingStore::new();
+
+            let (log, created) = match fs::read(path) {
+                Ok(log) => (log, false),
+                Err(err) if err.kind() == ErrorKind::NotFound => {
+                    (Vec::new(), true)
+                }
+                Err(_) => return Err(Error::Storage),
+            };
+
+            let len =
+                log.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+            let records =
+                std::str::from_utf8(&log[..len]).map_err(|_| Error::Storage)?;
+            for line in records.lines() {
+                let (pk, record) = parse_record(line)?;
+                memory.insert(&pk, &record)?;
+            }
+
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .map_err(|_| Error::Storage)?;
+
+            if len < log.len() {
+                file.set_len(len as u64)
+                    .and_then(|_| file.sync_all())
+                    .map_err(|_| Error::Storage)?;
+            }
+            if created {
+                sync_parent(path)?;
+            }
+
+            Ok(Self {
+                file,
+                len: len as u64,
+                memory,
+            })
+        }
+    }
+
+    /// Parse a line of the file into a record.
+    fn parse_record(line: &str) -> Result<(PublicKey, SigningRecord), Error> {
+        let fields: Vec<&str> = line.split(' ').collect();
+        let (pk, round, step, msg_hash) = match fields[..] {
+            [pk, round, step, msg_hash] => (pk, round, step, msg_hash),
+            _ => return Err(Error::Storage),
+        };
+
+        let pk = from_hex(pk).map_err(|_| Error::Storage)?;
+        let pk = PublicKey::from_bytes(&pk).map_err(|_| Error::Storage)?;
+        let record = SigningRecord {
+            round: round.parse().map_err(|_| Error::Storage)?,
+            step: step.parse().map_err(|_| Error::Storage)?,
+            msg_hash: from_hex(msg_hash).map_err(|_| Error::Storage)?,
+        };
+
+        Ok((pk, record))
+    }
+
+    /// Sync the directory of a newly created file, so that the file itself
+    /// survives a crash.
+    fn sync_parent(path: &Path) -> Result<(), Error> {
+        // directories can't be opened for syncing on other platforms
+        #[cfg(unix)]
+        {
+            let dir = match path.parent() {
+                Some(dir) if !dir.as_os_str().is_empty() => dir,
+                _ => Path::new("."),
+            };
+            File::open(dir)
+                .and_then(|dir| dir.sync_all())
+                .map_err(|_| Error::Storage)?;
+        }
+        #[cfg(not(unix))]
+        let _ = path;
+
+        Ok(())
+    }
+
+    impl SlashingStore for FileSlashingStore {
+        fn get(
+            &self,
+            pk: &PublicKey,
+            round: u64,
+            step: u8,
+        ) -> Result<Option<[u8; 32]>, Error> {
+            self.memory.get(pk, round, step)
+        }
+
+        fn insert(
+            &mut self,
+            pk: &PublicKey,
+            record: &SigningRecord,
+        ) -> Result<(), Error> {
+            let line = format!(
+                "{} {} {} {}\n",
+                to_hex(&pk.to_bytes()),
+                record.round,
+                record.step,
+                to_hex(&record.msg_hash),
+            );
+
+            // Don't keep in memory a record that wasn't persisted, nor a part
+            // of it in the file.
+            let written = self
+                .file
+                .write_all(line.as_bytes())
+                .and_then(|_| self.file.sync_all());
+            if written.is_err() {
+                let _ = self.file.set_len(self.len);
+                return Err(Error::Storage);
+            }
+            self.len += line.len() as u64;
+
+            self.memory.insert(pk, record)
+        }
+
+        fn records(&self) -> Result<Vec<(PublicKey, SigningRecord)>, Error> {
+            self.memory.records()
+        }
+    }
+}
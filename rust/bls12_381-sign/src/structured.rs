@@ -0,0 +1,176 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Canonical encoding of structured data for signing.
+//!
+//! The encoding of a value is unambiguous: integers are encoded in fixed width
+//! big-endian, variable length data is prefixed with its length, and fixed
+//! size data is encoded as is. The signed bytes are the encoding of the value
+//! prefixed with a caller chosen domain tag, so that values signed for one
+//! purpose can't be replayed for another.
+
+use crate::{Error, PublicKey, SecretKey, Signature, APK};
+#[cfg(feature = "hash-to-curve")]
+use crate::{PublicKeyG1, SignatureG2, APKG1};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use dusk_bytes::Serializable;
+
+/// Data that can be signed with `SecretKey::sign_structured`.
+pub trait Signable {
+    /// Append the canonical encoding of the value to `buf`.
+    fn encode(&self, buf: &mut Vec<u8>);
+
+    /// The bytes signed for the value under the domain tag `domain`.
+    fn signing_bytes(&self, domain: &[u8]) -> Vec<u8> {
+        let mut buf = Vec::new();
+
+        domain.encode(&mut buf);
+        self.encode(&mut buf);
+
+        buf
+    }
+}
+
+/// Encode the length of variable length data.
+fn encode_len(len: usize, buf: &mut Vec<u8>) {
+    (len as u64).encode(buf);
+}
+
+macro_rules! impl_signable_int {
+    ($($t:ty),*) => {
+        $(
+            impl Signable for $t {
+                fn encode(&self, buf: &mut Vec<u8>) {
+                    buf.extend_from_slice(&self.to_be_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_signable_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl Signable for bool {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(*self as u8);
+    }
+}
+
+impl<T: Signable> Signable for [T] {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        encode_len(self.len(), buf);
+        self.iter().for_each(|item| item.encode(buf));
+    }
+}
+
+impl<T: Signable, const N: usize> Signable for [T; N] {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        self.iter().for_each(|item| item.encode(buf));
+    }
+}
+
+impl<T: Signable> Signable for Vec<T> {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        self.as_slice().encode(buf);
+    }
+}
+
+impl Signable for str {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        self.as_bytes().encode(buf);
+    }
+}
+
+impl Signable for String {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        self.as_str().encode(buf);
+    }
+}
+
+impl<T: Signable> Signable for Option<T> {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            None => false.encode(buf),
+            Some(value) => {
+                true.encode(buf);
+                value.encode(buf);
+            }
+        }
+    }
+}
+
+impl<T: Signable + ?Sized> Signable for &T {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        (**self).encode(buf);
+    }
+}
+
+macro_rules! impl_signable_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: Signable),+> Signable for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn encode(&self, buf: &mut Vec<u8>) {
+                let ($($name,)+) = self;
+                $($name.encode(buf);)+
+            }
+        }
+    };
+}
+
+impl_signable_tuple!(A);
+impl_signable_tuple!(A, B);
+impl_signable_tuple!(A, B, C);
+impl_signable_tuple!(A, B, C, D);
+impl_signable_tuple!(A, B, C, D, E);
+impl_signable_tuple!(A, B, C, D, E, F);
+impl_signable_tuple!(A, B, C, D, E, F, G);
+impl_signable_tuple!(A, B, C, D, E, F, G, H);
+
+macro_rules! impl_signable_serializable {
+    ($($t:ty),*) => {
+        $(
+            impl Signable for $t {
+                fn encode(&self, buf: &mut Vec<u8>) {
+                    buf.extend_from_slice(&self.to_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_signable_serializable!(PublicKey, APK, Signature);
+
+#[cfg(feature = "hash-to-curve")]
+impl_signable_serializable!(PublicKeyG1, APKG1, SignatureG2);
+
+impl SecretKey {
+    /// Sign structured data in a rogue-key attack resistant way, under the
+    /// domain tag `domain`.
+    pub fn sign_structured<T: Signable + ?Sized>(
+        &self,
+        pk: &PublicKey,
+        domain: &[u8],
+        value: &T,
+    ) -> Signature {
+        self.sign(pk, &value.signing_bytes(domain))
+    }
+}
+
+impl APK {
+    /// Verify a [`Signature`] over structured data, produced with
+    /// `SecretKey::sign_structured` under the domain tag `domain`.
+    pub fn verify_structured<T: Signable + ?Sized>(
+        &self,
+        sig: &Signature,
+        domain: &[u8],
+        value: &T,
+    ) -> Result<(), Error> {
+        self.verify(sig, &value.signing_bytes(domain))
+    }
+}
@@ -0,0 +1,182 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Threshold signatures over the [`KeyShare`]s of a [`SecretKey`].
+//!
+//! Each holder of a share signs with `KeyShare::sign_partial`, and any
+//! `threshold` of the resulting [`PartialSignature`]s combine into the
+//! [`Signature`] the whole [`SecretKey`] would have produced, verifiable with
+//! `PublicKey::verify_threshold`.
+//!
+//! Messages are hashed to the curve with the hash-to-curve of the
+//! `BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_` ciphersuite, so signing with
+//! the shares is only available with the `hash-to-curve` feature: with the
+//! default hash, a single signature of a share would give away its signature
+//! over any other message.
+//!
+//! [`SecretKey`]: crate::SecretKey
+
+use crate::groups::{self, MinSig};
+#[cfg(feature = "hash-to-curve")]
+use crate::hash::h0_htc;
+use crate::shamir;
+use crate::{Error, KeyShare, PublicKey, Signature};
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::{G1Affine, G1Projective};
+use dusk_bytes::Serializable;
+
+#[cfg(feature = "rkyv-impl")]
+use rkyv::{Archive, Deserialize, Serialize};
+
+/// Domain separation tag of the messages signed with the shares, that of the
+/// `BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_` ciphersuite of the IETF BLS
+/// signature draft.
+#[cfg(feature = "hash-to-curve")]
+const THRESHOLD_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+
+/// A signature produced with a [`KeyShare`].
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv-impl",
+    derive(Archive, Deserialize, Serialize),
+    archive_attr(derive(bytecheck::CheckBytes))
+)]
+pub struct PartialSignature {
+    index: u8,
+    sig: Signature,
+}
+
+impl PartialSignature {
+    /// Index of the [`KeyShare`] that produced the signature.
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Verify the partial signature against the [`PublicKey`] of the
+    /// [`KeyShare`] that produced it, as returned by
+    /// [`KeyShare::public_key`].
+    #[cfg(feature = "hash-to-curve")]
+    pub fn verify(
+        &self,
+        share_pk: &PublicKey,
+        msg: &[u8],
+    ) -> Result<(), Error> {
+        self.verify_hashed(share_pk, &h0_htc(THRESHOLD_DST, msg))
+    }
+
+    /// Verify the partial signature over a message already hashed to the
+    /// point h.
+    #[cfg(feature = "hash-to-curve")]
+    pub(crate) fn verify_hashed(
+        &self,
+        share_pk: &PublicKey,
+        h: &G1Affine,
+    ) -> Result<(), Error> {
+        groups::verify_hashed::<MinSig>(&share_pk.0, &self.sig.0, h)
+    }
+
+    /// Combine partial signatures produced by distinct [`KeyShare`]s into a
+    /// [`Signature`], by Lagrange interpolation at zero.
+    ///
+    /// At least `threshold` valid partial signatures are needed for the
+    /// result to be valid, which isn't checked here. Fails with
+    /// [`Error::InvalidShares`] if no partial signature is given or if an
+    /// index is repeated.
+    pub fn combine(partials: &[PartialSignature]) -> Result<Signature, Error> {
+        if partials.is_empty() {
+            return Err(Error::InvalidShares);
+        }
+
+        let indexes: Vec<u8> = partials.iter().map(|p| p.index).collect();
+        let coefficients = shamir::lagrange_coefficients(&indexes)
+            .ok_or(Error::InvalidShares)?;
+
+        let sig: G1Projective = partials
+            .iter()
+            .zip(coefficients.iter())
+            .map(|(p, l)| p.sig.0 * l)
+            .sum();
+
+        Ok(Signature(sig.into()))
+    }
+}
+
+impl Serializable<49> for PartialSignature {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+
+        bytes[0] = self.index;
+        bytes[1..].copy_from_slice(&self.sig.to_bytes());
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        let mut sig = [0u8; Signature::SIZE];
+        sig.copy_from_slice(&bytes[1..]);
+
+        Ok(Self {
+            index: bytes[0],
+            sig: Signature(G1Affine::from_bytes(&sig)?),
+        })
+    }
+}
+
+impl KeyShare {
+    /// The [`PublicKey`] corresponding to the share, used to verify its
+    /// [`PartialSignature`]s.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(groups::public_key::<MinSig>(&self.value))
+    }
+
+    /// Sign a message with the share, producing a [`PartialSignature`].
+    #[cfg(feature = "hash-to-curve")]
+    pub fn sign_partial(&self, msg: &[u8]) -> PartialSignature {
+        self.sign_partial_hashed(&h0_htc(THRESHOLD_DST, msg))
+    }
+
+    /// Sign a message already hashed to the point h with the share.
+    #[cfg(feature = "hash-to-curve")]
+    pub(crate) fn sign_partial_hashed(&self, h: &G1Affine) -> PartialSignature {
+        let sig = groups::sign_hashed::<MinSig>(&self.value, h);
+
+        PartialSignature {
+            index: self.index,
+            sig: Signature(sig),
+        }
+    }
+}
+
+impl PublicKey {
+    /// Verify a [`Signature`] combined from the [`PartialSignature`]s of the
+    /// shares of the corresponding [`SecretKey`], by comparing the results of
+    /// the two pairing operations: e(sig, g_2) == e(Hₒ(m), pk).
+    ///
+    /// The public key must be valid, since the identity would accept the
+    /// identity as signature for any message.
+    ///
+    /// [`SecretKey`]: crate::SecretKey
+    #[cfg(feature = "hash-to-curve")]
+    pub fn verify_threshold(
+        &self,
+        sig: &Signature,
+        msg: &[u8],
+    ) -> Result<(), Error> {
+        if !self.is_valid() {
+            return Err(Error::InvalidSignature);
+        }
+
+        groups::verify_hashed::<MinSig>(
+            &self.0,
+            &sig.0,
+            &h0_htc(THRESHOLD_DST, msg),
+        )
+    }
+}
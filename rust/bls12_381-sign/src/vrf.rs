@@ -0,0 +1,122 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Verifiable random function built on the uniqueness of BLS signatures.
+//!
+//! The proof is a BLS signature over the input, hashed to the curve with
+//! `h0_htc` under a domain separation tag distinct from ordinary signing, and
+//! the output is the hash of the proof. Since the discrete logarithm of the
+//! hashed inputs is unknown, proofs over some inputs don't give away the
+//! outputs of the others.
+
+use crate::groups::{self, MinSig};
+use crate::hash::h0_htc;
+use crate::{Error, PublicKey, SecretKey, Signature};
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use dusk_bytes::Serializable;
+
+#[cfg(feature = "rkyv-impl")]
+use rkyv::{Archive, Deserialize, Serialize};
+
+/// Domain separation tag for hashing the input into the proof.
+const PROOF_DST: &[u8] = b"DUSK_BLS12381G1_XMD:SHA-256_SSWU_RO_VRF_";
+/// Domain separation tag for hashing the proof into the output.
+const OUTPUT_DST: &[u8] = b"DUSK_BLS12381_VRF_OUTPUT";
+
+/// The pseudo-random output of the VRF.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "rkyv-impl",
+    derive(Archive, Deserialize, Serialize),
+    archive_attr(derive(bytecheck::CheckBytes))
+)]
+pub struct VrfOutput([u8; 32]);
+
+impl VrfOutput {
+    fn from_proof(proof: &VrfProof) -> Self {
+        let digest = Blake2b::<U32>::new()
+            .chain_update(OUTPUT_DST)
+            .chain_update(proof.0.to_bytes())
+            .finalize();
+
+        Self(digest.into())
+    }
+}
+
+impl AsRef<[u8]> for VrfOutput {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Serializable<32> for VrfOutput {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        Ok(Self(*bytes))
+    }
+}
+
+/// The proof that a [`VrfOutput`] was correctly computed from an input.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv-impl",
+    derive(Archive, Deserialize, Serialize),
+    archive_attr(derive(bytecheck::CheckBytes))
+)]
+pub struct VrfProof(Signature);
+
+impl Serializable<48> for VrfProof {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        Ok(Self(Signature::from_bytes(bytes)?))
+    }
+}
+
+impl SecretKey {
+    /// Evaluate the VRF on `msg`, returning the output along with the proof
+    /// of its correctness.
+    pub fn vrf_prove(&self, msg: &[u8]) -> (VrfOutput, VrfProof) {
+        let h = h0_htc(PROOF_DST, msg);
+        let proof =
+            VrfProof(Signature(groups::sign_hashed::<MinSig>(&self.0, &h)));
+
+        (VrfOutput::from_proof(&proof), proof)
+    }
+}
+
+impl PublicKey {
+    /// Verify a [`VrfProof`] for `msg`, returning the [`VrfOutput`] it
+    /// proves.
+    ///
+    /// The public key must be valid, since the identity would accept the
+    /// identity as proof for any input.
+    pub fn vrf_verify(
+        &self,
+        msg: &[u8],
+        proof: &VrfProof,
+    ) -> Result<VrfOutput, Error> {
+        if !self.is_valid() {
+            return Err(Error::InvalidSignature);
+        }
+
+        let h = h0_htc(PROOF_DST, msg);
+        groups::verify_hashed::<MinSig>(&self.0, &(proof.0).0, &h)?;
+
+        Ok(VrfOutput::from_proof(proof))
+    }
+}
@@ -0,0 +1,89 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Witness generation for proving knowledge of valid signatures in a
+//! circuit.
+//!
+//! The signatures must be produced hashing the message with [`Poseidon`], so
+//! that the hash-to-curve step is cheap to constrain, e.g. with
+//! `SecretKey::sign_with::<Poseidon>` and verified with
+//! `APK::verify_with::<Poseidon>`.
+
+use crate::{HashToScalar, Poseidon, PublicKey, Signature, APK};
+
+use alloc::vec::Vec;
+
+use dusk_bls12_381::{pairing, BlsScalar, G1Affine, G2Affine, Gt};
+
+/// Hash a message into a [`BlsScalar`] with Poseidon, as done by the
+/// verification when hashing the message into G1.
+pub fn hash_to_scalar(msg: &[u8]) -> BlsScalar {
+    Poseidon::hash(msg)
+}
+
+/// The intermediate values of the verification of a [`Signature`] over a
+/// message hashed with [`Poseidon`]: e(sig, g_2) == e(Hₒ(m), pk).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyWitness {
+    /// The public key point, or the aggregated one when verifying with an
+    /// [`APK`].
+    pub pk: G2Affine,
+    /// The signature point.
+    pub sig: G1Affine,
+    /// The message encoded into the scalars absorbed by the sponge.
+    pub msg_scalars: Vec<BlsScalar>,
+    /// The Poseidon hash of the message scalars.
+    pub msg_hash: BlsScalar,
+    /// The message hashed into G1: g_1 * msg_hash.
+    pub h0m: G1Affine,
+    /// The left hand side of the verification: e(sig, g_2).
+    pub lhs: Gt,
+    /// The right hand side of the verification: e(Hₒ(m), pk).
+    pub rhs: Gt,
+}
+
+impl VerifyWitness {
+    fn new(pk: &G2Affine, sig: &G1Affine, msg: &[u8]) -> Self {
+        let msg_scalars = Poseidon::input_scalars(msg);
+        let msg_hash = dusk_poseidon::sponge::hash(&msg_scalars);
+        let h0m = (G1Affine::generator() * msg_hash).into();
+
+        let lhs = pairing(sig, &G2Affine::generator());
+        let rhs = pairing(&h0m, pk);
+
+        Self {
+            pk: *pk,
+            sig: *sig,
+            msg_scalars,
+            msg_hash,
+            h0m,
+            lhs,
+            rhs,
+        }
+    }
+
+    /// Whether the witnessed verification succeeds.
+    pub fn is_valid(&self) -> bool {
+        self.lhs == self.rhs
+    }
+}
+
+impl PublicKey {
+    /// Generate the witness of the verification of a [`Signature`] over a
+    /// message hashed with [`Poseidon`], as by `verify_with::<Poseidon>`.
+    pub fn verify_witness(&self, sig: &Signature, msg: &[u8]) -> VerifyWitness {
+        VerifyWitness::new(&self.0, &sig.0, msg)
+    }
+}
+
+impl APK {
+    /// Generate the witness of the verification of a [`Signature`] over a
+    /// message hashed with [`Poseidon`], as by `verify_with::<Poseidon>`.
+    /// Wrapper function for PublicKey.verify_witness.
+    pub fn verify_witness(&self, sig: &Signature, msg: &[u8]) -> VerifyWitness {
+        self.0.verify_witness(sig, msg)
+    }
+}
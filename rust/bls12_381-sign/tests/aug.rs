@@ -0,0 +1,140 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "hash-to-curve")]
+
+use dusk_bls12_381_sign::{PublicKey, SecretKey, Signature};
+use dusk_bytes::Serializable;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+#[test]
+fn aug_sign_verify() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let msg = random_message(rng);
+
+    let sig = sk.sign_aug(&pk, &msg);
+    assert!(pk.verify_aug(&sig, &msg).is_ok());
+
+    // The augmented signature is not a plain BLS signature.
+    assert!(pk.verify(&sig, &msg).is_err());
+
+    // Verification with a different message should fail.
+    let msg = random_message(rng);
+    assert!(pk.verify_aug(&sig, &msg).is_err());
+}
+
+#[test]
+fn aug_not_plain_signature_over_pk_and_message() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let msg = random_message(rng);
+
+    let mut augmented = pk.to_bytes().to_vec();
+    augmented.extend_from_slice(&msg);
+
+    // A plain signature over pk || m is not an augmented signature over m.
+    let sig = sk.sign_vulnerable(&augmented);
+    assert!(pk.verify_aug(&sig, &msg).is_err());
+    assert_ne!(sk.sign_aug(&pk, &msg), sig);
+}
+
+#[test]
+fn aug_reject_identity_public_key() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let pk = PublicKey::default();
+    let sig = Signature::default();
+    let msg = random_message(rng);
+
+    assert!(pk.is_identity());
+    assert!(pk.verify_aug(&sig, &msg).is_err());
+    assert!(sig.verify_aggregate_aug(&[(pk, &msg[..])]).is_err());
+}
+
+#[test]
+fn aug_sign_verify_incorrect_pk() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let msg = random_message(rng);
+
+    let sig = sk.sign_aug(&pk, &msg);
+
+    // Verification with a different public key should fail.
+    let pk = PublicKey::from(&SecretKey::random(rng));
+    assert!(pk.verify_aug(&sig, &msg).is_err());
+}
+
+#[test]
+fn aug_verify_aggregate_distinct_messages() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let mut signers = vec![];
+    let mut sigs = vec![];
+    for _ in 0..10 {
+        let sk = SecretKey::random(rng);
+        let pk = PublicKey::from(&sk);
+        let msg = random_message(rng);
+        sigs.push(sk.sign_aug(&pk, &msg));
+        signers.push((pk, msg));
+    }
+
+    let agg_sig = sigs[0].aggregate(&sigs[1..]);
+    let pairs: Vec<(PublicKey, &[u8])> =
+        signers.iter().map(|(pk, msg)| (*pk, &msg[..])).collect();
+    assert!(agg_sig.verify_aggregate_aug(&pairs).is_ok());
+
+    // Swapping the messages of two signers should fail.
+    let mut swapped = pairs.clone();
+    swapped[0].1 = pairs[1].1;
+    swapped[1].1 = pairs[0].1;
+    assert!(agg_sig.verify_aggregate_aug(&swapped).is_err());
+
+    // Leaving out a signer should fail.
+    assert!(agg_sig.verify_aggregate_aug(&pairs[1..]).is_err());
+
+    // An empty set of signers is never valid.
+    assert!(Signature::default().verify_aggregate_aug(&[]).is_err());
+}
+
+#[test]
+fn aug_known_answers() {
+    // Signatures of the `BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_AUG_`
+    // ciphersuite, pinning the encoding of the public key prepended to the
+    // message and the tag. With the key 1, the signature is the point the
+    // augmented message hashes to.
+    let cases: [(u8, &[u8], &str); 3] = [
+        (1, b"", "85e69e5f4d09f4c2b54cb1fa9ac88cb65896d799f3445981a387fd6939aa01178418f5d14dcf740077ebc20f1b402db7"),
+        (1, b"abc", "86c19b355e8bd6a1ce50dc4742caca7c4ea372b0ae28d210b90956bdb06e6b1e4bf06c2998bceb92cb0f7214eb1198ca"),
+        (42, b"abcdef0123456789", "96267dfad4e932b233fa88b5e3cbf69cb773ed3d45a4226f1220abe536b0a97755d2bb27b9b2cf3221bb1c399a8be807"),
+    ];
+
+    for (key, msg, expected) in cases {
+        let mut bytes = [0u8; 32];
+        bytes[0] = key;
+        let sk = SecretKey::from_bytes(&bytes).expect("valid key");
+        let pk = PublicKey::from(&sk);
+
+        let sig = sk.sign_aug(&pk, msg);
+        assert_eq!(hex::encode(sig.to_bytes()), expected);
+        assert!(pk.verify_aug(&sig, msg).is_ok());
+    }
+}
+
+fn random_message(rng: &mut StdRng) -> [u8; 100] {
+    let mut msg = [0u8; 100];
+
+    rng.fill_bytes(&mut msg);
+
+    msg
+}
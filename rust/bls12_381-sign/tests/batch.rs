@@ -0,0 +1,105 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bls12_381_sign::{
+    find_invalid_signers, verify_batch, PublicKey, SecretKey, Signature, APK,
+};
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+#[test]
+fn find_invalid_signers_all_valid() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+    let msg = random_message(rng);
+
+    let signers = signers(rng, &msg, 16);
+
+    assert!(find_invalid_signers(rng, &msg, &signers).is_empty());
+    assert!(find_invalid_signers(rng, &msg, &[]).is_empty());
+}
+
+#[test]
+fn find_invalid_signers_in_failed_aggregate() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+    let msg = random_message(rng);
+
+    let mut signers = signers(rng, &msg, 16);
+
+    // Replace some signatures with signatures over a different message.
+    let other_msg = random_message(rng);
+    for i in [0, 7, 8, 15] {
+        let sk = SecretKey::random(rng);
+        let pk = PublicKey::from(&sk);
+        signers[i] = (pk, sk.sign(&pk, &other_msg));
+    }
+
+    let mut apk = APK::from(&signers[0].0);
+    let pks: Vec<PublicKey> = signers[1..].iter().map(|(pk, _)| *pk).collect();
+    apk.aggregate(&pks);
+    let sigs: Vec<Signature> = signers[1..].iter().map(|(_, s)| *s).collect();
+    let agg_sig = signers[0].1.aggregate(&sigs);
+    assert!(apk.verify(&agg_sig, &msg).is_err());
+
+    assert_eq!(find_invalid_signers(rng, &msg, &signers), [0, 7, 8, 15]);
+}
+
+#[test]
+fn find_invalid_signers_single() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+    let msg = random_message(rng);
+
+    let mut signers = signers(rng, &msg, 13);
+
+    // Signature produced with a key other than the one it's paired with.
+    let sk = SecretKey::random(rng);
+    signers[11].1 = sk.sign(&signers[11].0, &msg);
+
+    assert_eq!(find_invalid_signers(rng, &msg, &signers), [11]);
+}
+
+#[test]
+fn verify_batch_distinct_messages() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let msgs: Vec<[u8; 100]> = (0..8).map(|_| random_message(rng)).collect();
+    let mut batch: Vec<(APK, Signature, &[u8])> = msgs
+        .iter()
+        .map(|msg| {
+            let sk = SecretKey::random(rng);
+            let pk = PublicKey::from(&sk);
+            (APK::from(&pk), sk.sign(&pk, msg), &msg[..])
+        })
+        .collect();
+
+    assert!(verify_batch(rng, &batch).is_ok());
+    assert!(verify_batch(rng, &[]).is_ok());
+
+    // A single entry over the wrong message invalidates the batch.
+    batch[0].2 = &msgs[1];
+    assert!(verify_batch(rng, &batch).is_err());
+}
+
+fn signers(
+    rng: &mut StdRng,
+    msg: &[u8],
+    n: usize,
+) -> Vec<(PublicKey, Signature)> {
+    (0..n)
+        .map(|_| {
+            let sk = SecretKey::random(rng);
+            let pk = PublicKey::from(&sk);
+            (pk, sk.sign(&pk, msg))
+        })
+        .collect()
+}
+
+fn random_message(rng: &mut StdRng) -> [u8; 100] {
+    let mut msg = [0u8; 100];
+
+    rng.fill_bytes(&mut msg);
+
+    msg
+}
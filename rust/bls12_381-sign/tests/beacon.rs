@@ -0,0 +1,148 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "hash-to-curve")]
+
+use dusk_bls12_381::G1Affine;
+use dusk_bls12_381_sign::{
+    Beacon, BeaconMode, HashToScalar, KeyShare, LegacyBlake2b,
+    PartialSignature, PublicKey, SecretKey, Signature,
+};
+use dusk_bytes::Serializable;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const THRESHOLD: u8 = 3;
+const MEMBERS: u8 = 5;
+
+/// Deal the shares of a group key to a committee.
+fn committee(rng: &mut StdRng) -> (PublicKey, Vec<KeyShare>) {
+    let sk = SecretKey::random(rng);
+    let shares = sk.split(rng, THRESHOLD, MEMBERS).expect("valid threshold");
+
+    (PublicKey::from(&sk), shares)
+}
+
+#[test]
+fn chained_beacon() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+    let (pk, shares) = committee(rng);
+    let beacon = Beacon::new(pk, BeaconMode::Chained);
+
+    let mut previous: Option<Signature> = None;
+    for round in 1..=5u64 {
+        // A different subset of the committee takes part in each round.
+        let members = (round as usize..round as usize + THRESHOLD as usize)
+            .map(|i| &shares[i % MEMBERS as usize]);
+
+        let partials: Vec<PartialSignature> = members
+            .map(|share| {
+                let partial =
+                    beacon.sign_partial(share, round, previous.as_ref());
+                assert!(beacon
+                    .verify_partial(
+                        &share.public_key(),
+                        round,
+                        previous.as_ref(),
+                        &partial
+                    )
+                    .is_ok());
+                partial
+            })
+            .collect();
+
+        let sig = beacon
+            .combine(round, previous.as_ref(), &partials)
+            .expect("enough valid partial signatures");
+        let randomness = beacon
+            .verify(round, previous.as_ref(), &sig)
+            .expect("valid round signature");
+        assert_eq!(randomness, Beacon::randomness(&sig));
+
+        // The round signature doesn't depend on the members taking part.
+        let others: Vec<PartialSignature> = shares[..THRESHOLD as usize]
+            .iter()
+            .map(|share| beacon.sign_partial(share, round, previous.as_ref()))
+            .collect();
+        assert_eq!(
+            PartialSignature::combine(&others).expect("distinct indexes"),
+            sig
+        );
+
+        // The round signature is bound to the previous one.
+        if let Some(previous) = previous {
+            assert!(beacon.verify(round, None, &sig).is_err());
+            assert!(beacon.verify(round + 1, Some(&previous), &sig).is_err());
+        }
+
+        previous = Some(sig);
+    }
+}
+
+#[test]
+fn unchained_beacon() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+    let (pk, shares) = committee(rng);
+    let beacon = Beacon::new(pk, BeaconMode::Unchained);
+
+    let round = 1000;
+    let partials: Vec<PartialSignature> = shares[2..]
+        .iter()
+        .map(|share| beacon.sign_partial(share, round, None))
+        .collect();
+    let sig = beacon
+        .combine(round, None, &partials)
+        .expect("enough valid partial signatures");
+
+    // Any round can be verified without the previous signature.
+    let other = SecretKey::random(rng).sign_vulnerable(b"previous");
+    assert_eq!(
+        beacon.verify(round, Some(&other), &sig),
+        beacon.verify(round, None, &sig)
+    );
+    assert!(beacon.verify(round + 1, None, &sig).is_err());
+}
+
+#[test]
+fn beacon_not_enough_partials() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+    let (pk, shares) = committee(rng);
+    let beacon = Beacon::new(pk, BeaconMode::Unchained);
+
+    let partials: Vec<PartialSignature> = shares[..THRESHOLD as usize - 1]
+        .iter()
+        .map(|share| beacon.sign_partial(share, 1, None))
+        .collect();
+    assert!(beacon.combine(1, None, &partials).is_err());
+
+    // Repeated partial signatures are rejected.
+    let repeated = [partials[0], partials[0], partials[1]];
+    assert!(beacon.combine(1, None, &repeated).is_err());
+}
+
+#[test]
+fn beacon_rounds_unpredictable() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+    let (pk, shares) = committee(rng);
+    let beacon = Beacon::new(pk, BeaconMode::Unchained);
+
+    let partials: Vec<PartialSignature> = shares[..THRESHOLD as usize]
+        .iter()
+        .map(|share| beacon.sign_partial(share, 1, None))
+        .collect();
+    let sig = beacon.combine(1, None, &partials).expect("valid");
+
+    // Were the rounds hashed to known multiples of the generator, the
+    // signature of a round would give away the signatures of all the others.
+    let k1 = LegacyBlake2b::hash(&beacon.message(1, None));
+    let k7 = LegacyBlake2b::hash(&beacon.message(7, None));
+    let sig = G1Affine::from_bytes(&sig.to_bytes()).expect("valid point");
+    let sk_g1 = sig * k1.invert().expect("non-zero scalar");
+    let forged = G1Affine::from(sk_g1 * k7);
+    let forged = Signature::from_bytes(&forged.to_bytes()).expect("valid");
+
+    assert!(beacon.verify(7, None, &forged).is_err());
+}
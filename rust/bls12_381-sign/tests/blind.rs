@@ -0,0 +1,103 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "hash-to-curve")]
+
+use dusk_bls12_381_sign::{
+    BlindSignature, BlindedMessage, BlindingFactor, PublicKey, SecretKey,
+};
+use dusk_bytes::Serializable;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn blind_sign_unblind_verify() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"anonymous token #1";
+
+    // Client
+    let (blinded, factor) = BlindedMessage::new(rng, msg);
+
+    // Signer
+    let blind_sig = sk.sign_blinded(&blinded);
+
+    // Client
+    assert!(blind_sig.verify(&pk, &blinded).is_ok());
+    let sig = blind_sig.unblind(&factor);
+
+    assert!(pk.verify_unblinded(&sig, msg).is_ok());
+    assert!(pk.verify_unblinded(&sig, b"anonymous token #2").is_err());
+
+    // Not an ordinary signature over the message.
+    assert!(pk.verify(&sig, msg).is_err());
+}
+
+#[test]
+fn blinded_messages_are_unlinkable() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let msg = b"anonymous token #1";
+
+    let (blinded_a, factor_a) = BlindedMessage::new(rng, msg);
+    let (blinded_b, factor_b) = BlindedMessage::new(rng, msg);
+    assert_ne!(blinded_a, blinded_b);
+
+    let blind_sig_a = sk.sign_blinded(&blinded_a);
+    let blind_sig_b = sk.sign_blinded(&blinded_b);
+    assert_ne!(blind_sig_a, blind_sig_b);
+
+    // Both unblind to the same signature.
+    assert_eq!(
+        blind_sig_a.unblind(&factor_a),
+        blind_sig_b.unblind(&factor_b)
+    );
+}
+
+#[test]
+fn blind_signature_invalid() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"anonymous token #1";
+
+    let (blinded, factor) = BlindedMessage::new(rng, msg);
+    let blind_sig = SecretKey::random(rng).sign_blinded(&blinded);
+
+    // Signed by another key.
+    assert!(blind_sig.verify(&pk, &blinded).is_err());
+    assert!(pk
+        .verify_unblinded(&blind_sig.unblind(&factor), msg)
+        .is_err());
+
+    // Unblinded with the wrong factor.
+    let (_, other_factor) = BlindedMessage::new(rng, msg);
+    let blind_sig = sk.sign_blinded(&blinded);
+    assert!(pk
+        .verify_unblinded(&blind_sig.unblind(&other_factor), msg)
+        .is_err());
+}
+
+#[test]
+fn blind_serialization() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let (blinded, factor) = BlindedMessage::new(rng, b"token");
+    let blind_sig = sk.sign_blinded(&blinded);
+
+    assert_eq!(BlindedMessage::from_bytes(&blinded.to_bytes()), Ok(blinded));
+    assert_eq!(
+        BlindSignature::from_bytes(&blind_sig.to_bytes()),
+        Ok(blind_sig)
+    );
+    assert_eq!(BlindingFactor::from_bytes(&factor.to_bytes()), Ok(factor));
+    assert!(BlindingFactor::from_bytes(&[0u8; 32]).is_err());
+}
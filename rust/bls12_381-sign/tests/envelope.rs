@@ -0,0 +1,86 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bls12_381_sign::{
+    Error, MemoryNonceStore, PublicKey, SecretKey, SignedEnvelope,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn envelope_window() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let envelope = SignedEnvelope::new(&sk, (7u64, *b"revoke"), 100, 200, 1);
+    assert_eq!(envelope.signer(), &PublicKey::from(&sk));
+
+    let store = &mut MemoryNonceStore::new();
+    assert_eq!(envelope.verify(99, store), Err(Error::NotYetValid));
+    assert_eq!(envelope.verify(201, store), Err(Error::Expired));
+    assert!(store.is_empty());
+
+    assert!(envelope.verify(100, store).is_ok());
+    let store = &mut MemoryNonceStore::new();
+    assert!(envelope.verify(200, store).is_ok());
+}
+
+#[test]
+fn envelope_replay() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let other_sk = SecretKey::random(rng);
+    let store = &mut MemoryNonceStore::new();
+
+    let envelope = SignedEnvelope::new(&sk, 1u8, 0, 10, 42);
+    assert!(envelope.verify(5, store).is_ok());
+    assert_eq!(envelope.verify(5, store), Err(Error::Replay));
+
+    // Nonces are per signer.
+    let other = SignedEnvelope::new(&other_sk, 1u8, 0, 10, 42);
+    assert!(other.verify(5, store).is_ok());
+
+    // Expired nonces can be pruned.
+    assert_eq!(store.len(), 2);
+    store.prune(11);
+    assert!(store.is_empty());
+}
+
+#[test]
+fn envelope_tampered() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let store = &mut MemoryNonceStore::new();
+
+    let envelope = SignedEnvelope::new(&sk, 1u8, 0, 10, 42);
+
+    // Extending the window or changing the nonce invalidates the signature.
+    let tampered = SignedEnvelope::from_parts(
+        *envelope.payload(),
+        *envelope.signer(),
+        0,
+        20,
+        42,
+        *envelope.signature(),
+    );
+    assert_eq!(tampered.verify(5, store), Err(Error::InvalidSignature));
+
+    let tampered = SignedEnvelope::from_parts(
+        *envelope.payload(),
+        *envelope.signer(),
+        0,
+        10,
+        43,
+        *envelope.signature(),
+    );
+    assert_eq!(tampered.verify(5, store), Err(Error::InvalidSignature));
+
+    // A rejected envelope doesn't consume its nonce.
+    assert!(store.is_empty());
+    assert!(envelope.verify(5, store).is_ok());
+}
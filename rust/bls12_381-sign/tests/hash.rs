@@ -0,0 +1,141 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use blake2::{Blake2b512, Digest};
+use dusk_bls12_381::BlsScalar;
+use dusk_bls12_381_sign::{
+    Blake2bWide, HashToScalar, LegacyBlake2b, MessageHasher, PublicKey,
+    SecretKey, APK,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const MSG: &[u8] = b"block 1234";
+
+fn sign_verify<H: HashToScalar>() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let sk2 = SecretKey::random(rng);
+    let pk2 = PublicKey::from(&sk2);
+
+    let mut apk = APK::from_with::<H>(&pk);
+    apk.aggregate_with::<H>(&[pk2]);
+
+    let sig = sk.sign_with::<H>(&pk, MSG);
+    let sig = sig.aggregate(&[sk2.sign_with::<H>(&pk2, MSG)]);
+    assert!(apk.verify_with::<H>(&sig, MSG).is_ok());
+    assert!(apk.verify_with::<H>(&sig, b"block 1235").is_err());
+
+    let sig = sk.sign_vulnerable_with::<H>(MSG);
+    assert!(pk.verify_with::<H>(&sig, MSG).is_ok());
+
+    let mut hasher = MessageHasher::<H>::default();
+    hasher.update(b"block ");
+    hasher.update(b"1234");
+    let hashed = hasher.finalize();
+    assert_eq!(
+        sk.sign_prehashed_with::<H>(&pk, &hashed),
+        sk.sign_with::<H>(&pk, MSG)
+    );
+    assert!(pk.verify_prehashed(&sig, &hashed).is_ok());
+}
+
+#[test]
+fn legacy_is_default() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+
+    let sig = sk.sign_with::<LegacyBlake2b>(&pk, MSG);
+    assert_eq!(sig, sk.sign(&pk, MSG));
+    assert_eq!(APK::from_with::<LegacyBlake2b>(&pk), APK::from(&pk));
+    assert!(APK::from(&pk).verify(&sig, MSG).is_ok());
+
+    sign_verify::<LegacyBlake2b>();
+}
+
+#[test]
+fn blake2b_wide() {
+    sign_verify::<Blake2bWide>();
+
+    // A 64 bytes digest is reduced as a whole.
+    let mut digest = [0u8; 64];
+    digest.copy_from_slice(
+        &Blake2b512::new()
+            .chain_update([0])
+            .chain_update(MSG)
+            .finalize(),
+    );
+    assert_eq!(Blake2bWide::hash(MSG), BlsScalar::from_bytes_wide(&digest));
+}
+
+#[test]
+fn hashes_are_not_interchangeable() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+
+    let sig = sk.sign_with::<Blake2bWide>(&pk, MSG);
+    assert!(APK::from(&pk).verify(&sig, MSG).is_err());
+    assert!(APK::from_with::<Blake2bWide>(&pk)
+        .verify(&sig, MSG)
+        .is_err());
+    assert!(APK::from_with::<Blake2bWide>(&pk)
+        .verify_with::<Blake2bWide>(&sig, MSG)
+        .is_ok());
+}
+
+#[cfg(feature = "sha2")]
+#[test]
+fn sha256_wide() {
+    use dusk_bls12_381_sign::Sha256Wide;
+    use sha2::Sha256;
+
+    sign_verify::<Sha256Wide>();
+
+    // A 32 bytes digest is computed twice with distinct prefixes.
+    let mut digest = [0u8; 64];
+    digest[..32].copy_from_slice(
+        &Sha256::new().chain_update([0]).chain_update(MSG).finalize(),
+    );
+    digest[32..].copy_from_slice(
+        &Sha256::new().chain_update([1]).chain_update(MSG).finalize(),
+    );
+    assert_eq!(Sha256Wide::hash(MSG), BlsScalar::from_bytes_wide(&digest));
+}
+
+#[cfg(feature = "blake3")]
+#[test]
+fn blake3_wide() {
+    sign_verify::<dusk_bls12_381_sign::Blake3Wide>();
+}
+
+#[cfg(feature = "poseidon")]
+#[test]
+fn poseidon() {
+    use dusk_bls12_381_sign::Poseidon;
+
+    sign_verify::<Poseidon>();
+
+    // The input length is part of the sponge input.
+    assert_eq!(Poseidon::input_scalars(&[0u8; 31]).len(), 2);
+    assert_eq!(Poseidon::input_scalars(&[0u8; 32]).len(), 3);
+    assert_ne!(Poseidon::hash(&[0u8; 31]), Poseidon::hash(&[0u8; 30]));
+
+    // The input may be split anywhere across the updates.
+    let data: Vec<u8> = (0..200).map(|i| i as u8).collect();
+    for split in [0, 1, 30, 31, 32, 62, 124, 199, 200] {
+        let mut hasher = Poseidon::default();
+        hasher.update(&data[..split]);
+        hasher.update(&[]);
+        hasher.update(&data[split..]);
+        assert_eq!(hasher.finalize(), Poseidon::hash(&data));
+    }
+}
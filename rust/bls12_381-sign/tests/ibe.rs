@@ -0,0 +1,121 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "hash-to-curve")]
+
+use dusk_bls12_381_sign::{
+    Beacon, BeaconMode, Ciphertext, Error, PartialSignature, PublicKey,
+    SecretKey,
+};
+use dusk_bytes::DeserializableSlice;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn encrypt_decrypt() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let id = b"alice@dusk.network";
+
+    for msg in [&b""[..], b"hello", &[0xab; 200][..]] {
+        let ct = pk.encrypt(rng, id, msg).expect("encodable");
+        let key = sk.decryption_key(id);
+        assert_eq!(key.decrypt(&ct).as_deref(), Ok(msg));
+
+        // Round trip through serialization.
+        let ct = Ciphertext::from_slice(&ct.to_bytes()).expect("valid bytes");
+        assert_eq!(key.decrypt(&ct).as_deref(), Ok(msg));
+    }
+}
+
+/// Master secret key of the known ciphertext.
+const KNOWN_SK: &str =
+    "ed5e000000000000000000000000000000000000000000000000000000000000";
+/// Identity the known ciphertext is encrypted to.
+const KNOWN_ID: &[u8] = b"auction 1: round 7";
+/// Message of the known ciphertext.
+const KNOWN_MSG: &[u8] = b"sealed bid: 1000";
+/// Ciphertext of the known message, which must stay decryptable.
+const KNOWN_CT: &str = concat!(
+    "b92d1d08841f54f863217eae4065927a177a3d335e6f54487b7e87eddfd1ba59",
+    "cd03b23bf0302cbe20d59c797137e3f8126c9ca536b89e5b3d09421e1188eb65",
+    "e26df534a42e1bb70a1067d06d097441a12ac60d4735fd402cecba64a9682199",
+    "57cb937a246307b25820fdf4214e9d0805d228e08d9b98b02fdfa54e6e4bc2e1",
+    "1028333b4501e5525fc841d77a817919",
+);
+
+#[test]
+fn decrypt_known_ciphertext() {
+    let sk = hex::decode(KNOWN_SK).expect("valid hex");
+    let sk = SecretKey::from_slice(&sk).expect("valid secret key");
+    let ct = hex::decode(KNOWN_CT).expect("valid hex");
+    let ct = Ciphertext::from_slice(&ct).expect("valid ciphertext");
+
+    let key = sk.decryption_key(KNOWN_ID);
+    assert_eq!(key.decrypt(&ct).as_deref(), Ok(KNOWN_MSG));
+}
+
+#[test]
+fn decrypt_invalid() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"sealed bid: 1000";
+
+    let ct = pk.encrypt(rng, b"alice", msg).expect("encodable");
+
+    // The key of another identity can't decrypt.
+    let key = sk.decryption_key(b"bob");
+    assert_eq!(key.decrypt(&ct), Err(Error::InvalidCiphertext));
+
+    // A tampered ciphertext is rejected.
+    let key = sk.decryption_key(b"alice");
+    let mut bytes = ct.to_bytes();
+    *bytes.last_mut().unwrap() ^= 0x01;
+    let tampered = Ciphertext::from_slice(&bytes).expect("valid bytes");
+    assert_eq!(key.decrypt(&tampered), Err(Error::InvalidCiphertext));
+
+    // A truncated ciphertext can't be deserialized.
+    assert!(
+        Ciphertext::from_slice(&bytes[..Ciphertext::HEADER_SIZE - 1]).is_err()
+    );
+}
+
+#[test]
+fn timelock_encryption() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let shares = sk.split(rng, 2, 3).expect("valid threshold");
+    let beacon = Beacon::new(PublicKey::from(&sk), BeaconMode::Unchained);
+
+    // Encrypt a bid to a future round of the beacon.
+    let round = 7;
+    let bid = b"sealed bid: 1000";
+    let ct = beacon
+        .public_key()
+        .encrypt(rng, &beacon.message(round, None), bid)
+        .expect("encodable");
+
+    // The signature of another round doesn't decrypt.
+    let early: Vec<PartialSignature> = shares[..2]
+        .iter()
+        .map(|share| beacon.sign_partial(share, round - 1, None))
+        .collect();
+    let early = beacon.combine(round - 1, None, &early).expect("valid");
+    assert!(early.decrypt(&ct).is_err());
+
+    // Once the round is reached its signature decrypts the bid.
+    let partials: Vec<PartialSignature> = shares[1..]
+        .iter()
+        .map(|share| beacon.sign_partial(share, round, None))
+        .collect();
+    let sig = beacon.combine(round, None, &partials).expect("valid");
+    assert_eq!(sig.decrypt(&ct).as_deref(), Ok(&bid[..]));
+}
@@ -0,0 +1,60 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bls12_381_sign::{
+    HashedMessage, MessageHasher, PublicKey, SecretKey, APK,
+};
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+#[test]
+fn streaming_matches_whole_message() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let mut msg = vec![0u8; 10_000];
+    rng.fill_bytes(&mut msg);
+
+    let mut hasher = MessageHasher::new();
+    msg.chunks(333).for_each(|chunk| hasher.update(chunk));
+    let hashed = hasher.finalize();
+
+    assert_eq!(hashed, HashedMessage::from(&msg[..]));
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let apk = APK::from(&pk);
+
+    // Prehashed and in memory signatures are interchangeable.
+    let sig = sk.sign_prehashed(&pk, &hashed);
+    assert_eq!(sig, sk.sign(&pk, &msg));
+    assert!(apk.verify_prehashed(&sig, &hashed).is_ok());
+    assert!(apk.verify(&sig, &msg).is_ok());
+
+    let sig = sk.sign(&pk, &msg);
+    assert!(apk.verify_prehashed(&sig, &hashed).is_ok());
+
+    let sig = sk.sign_vulnerable(&msg);
+    assert!(pk.verify_prehashed(&sig, &hashed).is_ok());
+}
+
+#[test]
+fn prehashed_wrong_message() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let apk = APK::from(&pk);
+
+    let mut hasher = MessageHasher::new();
+    hasher.update(b"snapshot ");
+    hasher.update(b"part 1");
+    let sig = sk.sign_prehashed(&pk, &hasher.finalize());
+
+    let mut hasher = MessageHasher::new();
+    hasher.update(b"snapshot ");
+    hasher.update(b"part 2");
+    assert!(apk.verify_prehashed(&sig, &hasher.finalize()).is_err());
+}
@@ -0,0 +1,120 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "hash-to-curve")]
+
+use dusk_bls12_381::{BlsScalar, G1Affine};
+use dusk_bls12_381_sign::{
+    HashToScalar, KeyRotation, LegacyBlake2b, PublicKey, SecretKey,
+};
+use dusk_bytes::Serializable;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn rotation_verify() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let old_sk = SecretKey::random(rng);
+    let new_sk = SecretKey::random(rng);
+
+    let rotation = KeyRotation::new(&old_sk, &new_sk);
+    assert!(rotation.verify().is_ok());
+    assert_eq!(rotation.old_public_key(), &PublicKey::from(&old_sk));
+    assert_eq!(rotation.new_public_key(), &PublicKey::from(&new_sk));
+
+    // Round trip the record through its serialization.
+    let bytes = rotation.to_bytes();
+    let rotation = KeyRotation::from_bytes(&bytes).unwrap();
+    assert!(rotation.verify().is_ok());
+}
+
+#[test]
+fn rotation_tampered() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let old_sk = SecretKey::random(rng);
+    let new_sk = SecretKey::random(rng);
+    let other_sk = SecretKey::random(rng);
+
+    let rotation = KeyRotation::new(&old_sk, &new_sk);
+    let other = KeyRotation::new(&old_sk, &other_sk);
+
+    let bytes = rotation.to_bytes();
+    let other_bytes = other.to_bytes();
+
+    // Replacing the new key, keeping the endorsement of the old one, fails.
+    let mut tampered = bytes;
+    tampered[96..192].copy_from_slice(&other_bytes[96..192]);
+    tampered[240..].copy_from_slice(&other_bytes[240..]);
+    let tampered = KeyRotation::from_bytes(&tampered).unwrap();
+    assert!(tampered.verify().is_err());
+
+    // Replacing the proof of possession of the new key fails.
+    let mut tampered = bytes;
+    tampered[240..].copy_from_slice(&other_bytes[240..]);
+    let tampered = KeyRotation::from_bytes(&tampered).unwrap();
+    assert!(tampered.verify().is_err());
+
+    // The endorsement can't be used as proof of possession.
+    let mut swapped = bytes;
+    swapped[..96].copy_from_slice(&bytes[96..192]);
+    swapped[96..192].copy_from_slice(&bytes[..96]);
+    swapped[192..240].copy_from_slice(&bytes[240..]);
+    swapped[240..].copy_from_slice(&bytes[192..240]);
+    let swapped = KeyRotation::from_bytes(&swapped).unwrap();
+    assert!(swapped.verify().is_err());
+}
+
+/// Hash of the pair of public keys to a multiple of the generator, as
+/// `h0` would.
+fn legacy_hash(
+    dst: &[u8],
+    old_pk: &PublicKey,
+    new_pk: &PublicKey,
+) -> BlsScalar {
+    let mut hasher = LegacyBlake2b::default();
+    hasher.update(dst);
+    hasher.update(&[dst.len() as u8]);
+    hasher.update(&old_pk.to_bytes());
+    hasher.update(&new_pk.to_bytes());
+    hasher.finalize()
+}
+
+#[test]
+fn rotation_not_forged_from_signature() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let old_sk = SecretKey::random(rng);
+    let old_pk = PublicKey::from(&old_sk);
+    let msg = b"any message";
+    let sig = old_sk.sign_vulnerable(msg);
+
+    // Were the pair of keys hashed to a known multiple of the generator, any
+    // signature of the old key would give away its endorsement of a new key.
+    let s = BlsScalar::from(0xbad);
+    let new_pk = PublicKey::from(&SecretKey::from(s));
+
+    let k = LegacyBlake2b::hash(msg);
+    let k_end =
+        legacy_hash(b"DUSK_BLS12381_ROTATION_ENDORSEMENT", &old_pk, &new_pk);
+    let k_pos =
+        legacy_hash(b"DUSK_BLS12381_ROTATION_POSSESSION", &old_pk, &new_pk);
+
+    let sig = G1Affine::from_bytes(&sig.to_bytes()).expect("valid point");
+    let old_sk_g1 = sig * k.invert().expect("non-zero scalar");
+    let endorsement = G1Affine::from(old_sk_g1 * k_end);
+    let possession = G1Affine::from(G1Affine::generator() * (k_pos * s));
+
+    let mut bytes = [0u8; KeyRotation::SIZE];
+    bytes[..96].copy_from_slice(&old_pk.to_bytes());
+    bytes[96..192].copy_from_slice(&new_pk.to_bytes());
+    bytes[192..240].copy_from_slice(&endorsement.to_bytes());
+    bytes[240..].copy_from_slice(&possession.to_bytes());
+    let forged = KeyRotation::from_bytes(&bytes).expect("valid points");
+
+    assert!(forged.verify().is_err());
+}
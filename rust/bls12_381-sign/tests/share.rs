@@ -0,0 +1,83 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bls12_381_sign::{Error, KeyShare, PublicKey, SecretKey};
+use dusk_bytes::Serializable;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn split_recover() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+
+    let shares = sk.split(rng, 3, 5).expect("valid threshold");
+    assert_eq!(shares.len(), 5);
+
+    // Any 3 of the 5 shares recover the key.
+    for subset in [[0, 1, 2], [0, 2, 4], [4, 3, 1]] {
+        let subset: Vec<KeyShare> = subset.iter().map(|&i| shares[i]).collect();
+        assert_eq!(SecretKey::recover(&subset, &pk), Ok(sk));
+    }
+
+    // Two shares are not enough.
+    assert_eq!(
+        SecretKey::recover(&shares[..2], &pk),
+        Err(Error::InvalidShares)
+    );
+
+    // A repeated share doesn't count twice.
+    let repeated = [shares[0], shares[0], shares[1]];
+    assert_eq!(
+        SecretKey::recover(&repeated, &pk),
+        Err(Error::InvalidShares)
+    );
+
+    // The recovered key is confirmed against the public key.
+    let other_pk = PublicKey::from(&SecretKey::random(rng));
+    assert_eq!(
+        SecretKey::recover(&shares, &other_pk),
+        Err(Error::InvalidShares)
+    );
+}
+
+#[test]
+fn split_invalid_threshold() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+
+    assert_eq!(sk.split(rng, 0, 5), Err(Error::InvalidThreshold));
+    assert_eq!(sk.split(rng, 6, 5), Err(Error::InvalidThreshold));
+    assert_eq!(sk.split(rng, 1, 1).map(|shares| shares.len()), Ok(1));
+}
+
+#[test]
+fn share_serialization() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+
+    let shares = sk.split(rng, 2, 3).expect("valid threshold");
+    let mut bytes: Vec<[u8; KeyShare::SIZE]> =
+        shares.iter().map(|share| share.to_bytes()).collect();
+
+    let decoded: Vec<KeyShare> = bytes
+        .iter()
+        .map(|b| KeyShare::from_bytes(b).expect("valid share"))
+        .collect();
+    assert_eq!(decoded, shares);
+    assert_eq!(decoded[1].index(), 2);
+    assert_eq!(decoded[1].threshold(), 2);
+    assert_eq!(SecretKey::recover(&decoded[1..], &pk), Ok(sk));
+
+    // A corrupted share fails the checksum.
+    bytes[0][10] ^= 0x01;
+    assert!(KeyShare::from_bytes(&bytes[0]).is_err());
+}
@@ -0,0 +1,103 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "hash-to-curve")]
+
+use dusk_bls12_381_sign::{PublicKeyG1, SecretKey, SignatureG2, APKG1};
+use dusk_bytes::Serializable;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+#[test]
+fn vulnerable_sign_verify_g2() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKeyG1::from(&sk);
+    let msg = random_message(rng);
+
+    let sig = sk.sign_vulnerable_g2(&msg);
+    assert!(pk.verify(&sig, &msg).is_ok());
+
+    // Verification with a different message should fail.
+    let msg = random_message(rng);
+    assert!(pk.verify(&sig, &msg).is_err());
+}
+
+#[test]
+fn sign_verify_g2() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKeyG1::from(&sk);
+    let msg = random_message(rng);
+
+    let sig = sk.sign_g2(&pk, &msg);
+
+    // Verification with the standard pk should fail.
+    assert!(pk.verify(&sig, &msg).is_err());
+
+    // Verification with the aggregated version should work.
+    let apk = APKG1::from(&pk);
+    assert!(apk.verify(&sig, &msg).is_ok());
+
+    // Verification with another APK should fail.
+    let apk = APKG1::from(&SecretKey::random(rng));
+    assert!(apk.verify(&sig, &msg).is_err());
+}
+
+#[test]
+fn sign_verify_aggregated_g2() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKeyG1::from(&sk);
+    let msg = random_message(rng);
+
+    let mut agg_sig = sk.sign_g2(&pk, &msg);
+    let mut apk = APKG1::from(&pk);
+
+    let mut pks = vec![];
+    for _ in 0..10 {
+        let sk = SecretKey::random(rng);
+        let pk = PublicKeyG1::from(&sk);
+        let sig = sk.sign_g2(&pk, &msg);
+        agg_sig = agg_sig.aggregate(&[sig]);
+        pks.push(pk)
+    }
+    apk.aggregate(&pks[..]);
+
+    assert!(apk.verify(&agg_sig, &msg).is_ok());
+
+    // Verification should fail with a different message.
+    let msg = random_message(rng);
+    assert!(apk.verify(&agg_sig, &msg).is_err());
+}
+
+#[test]
+fn serialization_g2() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKeyG1::from(&sk);
+    let apk = APKG1::from(&pk);
+    let sig = sk.sign_g2(&pk, &random_message(rng));
+
+    assert_eq!(PublicKeyG1::SIZE, 48);
+    assert_eq!(SignatureG2::SIZE, 96);
+
+    assert_eq!(PublicKeyG1::from_bytes(&pk.to_bytes()), Ok(pk));
+    assert_eq!(APKG1::from_bytes(&apk.to_bytes()), Ok(apk));
+    assert_eq!(SignatureG2::from_bytes(&sig.to_bytes()), Ok(sig));
+}
+
+fn random_message(rng: &mut StdRng) -> [u8; 100] {
+    let mut msg = [0u8; 100];
+
+    rng.fill_bytes(&mut msg);
+
+    msg
+}
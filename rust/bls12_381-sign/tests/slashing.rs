@@ -0,0 +1,210 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bls12_381_sign::{
+    Error, MemorySlashingStore, SecretKey, SigningRecord, SlashingProtectedKey,
+    SlashingStore, APK,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn refuse_double_sign() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let apk = APK::from(&sk);
+    let mut key = SlashingProtectedKey::new(sk, MemorySlashingStore::new());
+
+    let sig = key.sign(1, 0, b"block a").unwrap();
+    assert!(apk.verify(&sig, b"block a").is_ok());
+
+    // Signing the same message again is allowed, a different one isn't.
+    assert_eq!(key.sign(1, 0, b"block a"), Ok(sig));
+    assert_eq!(key.sign(1, 0, b"block b"), Err(Error::DoubleSign));
+
+    // Other steps and rounds are independent.
+    assert!(key.sign(1, 1, b"block b").is_ok());
+    assert!(key.sign(2, 0, b"block b").is_ok());
+
+    let records = key.store().records().unwrap();
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0].1, SigningRecord::new(1, 0, b"block a"));
+}
+
+#[test]
+fn store_shared_by_signers() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let other_sk = SecretKey::random(rng);
+
+    let mut key = SlashingProtectedKey::new(sk, MemorySlashingStore::new());
+    assert!(key.sign(1, 0, b"block a").is_ok());
+
+    let mut other = SlashingProtectedKey::new(other_sk, key.into_store());
+    assert!(other.sign(1, 0, b"block b").is_ok());
+
+    let mut key = SlashingProtectedKey::new(sk, other.into_store());
+    assert_eq!(key.sign(1, 0, b"block b"), Err(Error::DoubleSign));
+}
+
+#[cfg(feature = "interchange")]
+mod interchange {
+    use super::*;
+    use dusk_bls12_381_sign::{
+        export_interchange, import_interchange, PublicKey,
+    };
+    use dusk_bytes::Serializable;
+
+    #[test]
+    fn interchange_round_trip() {
+        let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+        let sk = SecretKey::random(rng);
+        let mut key = SlashingProtectedKey::new(sk, MemorySlashingStore::new());
+        key.sign(1, 0, b"block a").unwrap();
+        key.sign(1, 1, b"vote a").unwrap();
+
+        let json = export_interchange(key.store()).unwrap();
+        assert!(json.contains("\"interchange_format_version\": \"5\""));
+
+        let mut store = MemorySlashingStore::new();
+        import_interchange(&mut store, &json).unwrap();
+        assert_eq!(store.records(), key.store().records());
+
+        // Importing twice is harmless.
+        import_interchange(&mut store, &json).unwrap();
+
+        let mut key = SlashingProtectedKey::new(sk, store);
+        assert_eq!(key.sign(1, 1, b"vote b"), Err(Error::DoubleSign));
+
+        // Conflicting records are refused as a whole.
+        let mut other =
+            SlashingProtectedKey::new(sk, MemorySlashingStore::new());
+        other.sign(2, 0, b"block c").unwrap();
+        other.sign(1, 0, b"block b").unwrap();
+        let json = export_interchange(other.store()).unwrap();
+
+        let mut store = key.into_store();
+        assert_eq!(
+            import_interchange(&mut store, &json),
+            Err(Error::DoubleSign)
+        );
+        assert_eq!(store.records().unwrap().len(), 2);
+
+        assert_eq!(
+            import_interchange(&mut store, "{}"),
+            Err(Error::InvalidInterchange)
+        );
+    }
+
+    #[test]
+    fn interchange_self_conflicting() {
+        let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+        let sk = SecretKey::random(rng);
+        let pk = hex::encode(PublicKey::from(&sk).to_bytes());
+        let a = hex::encode(SigningRecord::new(1, 0, b"block a").msg_hash);
+        let b = hex::encode(SigningRecord::new(1, 0, b"block b").msg_hash);
+
+        // Two different messages signed for the same round and step.
+        let json = format!(
+            r#"{{
+                "metadata": {{ "interchange_format_version": "5" }},
+                "data": [{{
+                    "pubkey": "0x{pk}",
+                    "signed_votes": [
+                        {{ "round": "2", "step": "0", "message_hash": "0x{a}" }},
+                        {{ "round": "1", "step": "0", "message_hash": "0x{a}" }},
+                        {{ "round": "1", "step": "0", "message_hash": "0x{b}" }}
+                    ]
+                }}]
+            }}"#
+        );
+
+        let mut store = MemorySlashingStore::new();
+        assert_eq!(
+            import_interchange(&mut store, &json),
+            Err(Error::DoubleSign)
+        );
+        assert!(store.records().unwrap().is_empty());
+    }
+}
+
+#[cfg(feature = "std")]
+mod file {
+    use super::*;
+    use dusk_bls12_381_sign::FileSlashingStore;
+    use std::io::Write;
+
+    #[test]
+    fn file_store() {
+        let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+        let path = std::env::temp_dir().join("bls12_381-sign-slashing.log");
+        let _ = std::fs::remove_file(&path);
+
+        let sk = SecretKey::random(rng);
+
+        let store = FileSlashingStore::open(&path).unwrap();
+        let mut key = SlashingProtectedKey::new(sk, store);
+        key.sign(7, 2, b"vote a").unwrap();
+        key.sign(7, 3, b"vote a").unwrap();
+        drop(key);
+
+        // The records survive reopening the store.
+        let store = FileSlashingStore::open(&path).unwrap();
+        let mut key = SlashingProtectedKey::new(sk, store);
+        assert_eq!(key.store().records().unwrap().len(), 2);
+        assert_eq!(key.sign(7, 2, b"vote b"), Err(Error::DoubleSign));
+        assert!(key.sign(7, 2, b"vote a").is_ok());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn file_store_torn_record() {
+        let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+        let path = std::env::temp_dir().join("bls12_381-sign-torn.log");
+        let _ = std::fs::remove_file(&path);
+
+        let sk = SecretKey::random(rng);
+
+        let store = FileSlashingStore::open(&path).unwrap();
+        let mut key = SlashingProtectedKey::new(sk, store);
+        key.sign(1, 0, b"block a").unwrap();
+        drop(key);
+
+        // A crash while appending a record leaves it incomplete.
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        file.write_all(b"0x8a53eb").unwrap();
+        drop(file);
+
+        // The incomplete record is discarded, and the others kept.
+        let store = FileSlashingStore::open(&path).unwrap();
+        let mut key = SlashingProtectedKey::new(sk, store);
+        assert_eq!(key.sign(1, 0, b"block b"), Err(Error::DoubleSign));
+        key.sign(2, 0, b"block b").unwrap();
+        drop(key);
+
+        let store = FileSlashingStore::open(&path).unwrap();
+        assert_eq!(store.records().unwrap().len(), 2);
+
+        // Complete but malformed records are an error.
+        std::fs::write(&path, "0x8a53eb 1 0\n").unwrap();
+        assert_eq!(
+            FileSlashingStore::open(&path).map(|_| ()),
+            Err(Error::Storage)
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
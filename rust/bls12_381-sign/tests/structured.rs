@@ -0,0 +1,71 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use dusk_bls12_381_sign::{PublicKey, SecretKey, Signable, APK};
+use dusk_bytes::Serializable;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const DOMAIN: &[u8] = b"vote";
+
+#[test]
+fn canonical_encoding() {
+    let mut buf = vec![];
+    (1u8, 2u16, -1i32, true).encode(&mut buf);
+    assert_eq!(buf, [1, 0, 2, 0xff, 0xff, 0xff, 0xff, 1]);
+
+    let mut buf = vec![];
+    (&b"ab"[..], [7u8; 2], Some(3u8), None::<u8>).encode(&mut buf);
+    assert_eq!(buf, [0, 0, 0, 0, 0, 0, 0, 2, b'a', b'b', 7, 7, 1, 3, 0]);
+
+    let mut buf = vec![];
+    "hi".encode(&mut buf);
+    assert_eq!(buf, [0, 0, 0, 0, 0, 0, 0, 2, b'h', b'i']);
+
+    assert_eq!(
+        1u64.signing_bytes(b"d"),
+        [0, 0, 0, 0, 0, 0, 0, 1, b'd', 0, 0, 0, 0, 0, 0, 0, 1]
+    );
+}
+
+#[test]
+fn encoding_is_unambiguous() {
+    // The boundary between variable length fields is part of the encoding.
+    let a = (&b"ab"[..], &b"c"[..]).signing_bytes(DOMAIN);
+    let b = (&b"a"[..], &b"bc"[..]).signing_bytes(DOMAIN);
+    assert_ne!(a, b);
+
+    // So is the boundary between the domain and the value.
+    let a = b"c"[..].signing_bytes(b"ab");
+    let b = b"bc"[..].signing_bytes(b"a");
+    assert_ne!(a, b);
+}
+
+#[test]
+fn sign_verify_structured() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let apk = APK::from(&pk);
+
+    let vote = (42u64, 3u8, [0xabu8; 32], pk);
+    let sig = sk.sign_structured(&pk, DOMAIN, &vote);
+    assert!(apk.verify_structured(&sig, DOMAIN, &vote).is_ok());
+
+    // Equivalent to signing the encoded bytes.
+    assert_eq!(sig, sk.sign(&pk, &vote.signing_bytes(DOMAIN)));
+
+    // A different domain or value fails.
+    assert!(apk.verify_structured(&sig, b"header", &vote).is_err());
+    let other = (43u64, 3u8, [0xabu8; 32], pk);
+    assert!(apk.verify_structured(&sig, DOMAIN, &other).is_err());
+
+    // The key types encode as their serialization.
+    let mut buf = vec![];
+    pk.encode(&mut buf);
+    assert_eq!(buf, pk.to_bytes());
+}
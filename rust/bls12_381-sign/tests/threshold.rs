@@ -0,0 +1,54 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "hash-to-curve")]
+
+use dusk_bls12_381_sign::{PartialSignature, PublicKey, SecretKey};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn threshold_sign_verify() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let shares = sk.split(rng, 3, 5).expect("valid threshold");
+    let msg = b"threshold message";
+
+    let partials: Vec<PartialSignature> =
+        shares.iter().map(|share| share.sign_partial(msg)).collect();
+
+    for (share, partial) in shares.iter().zip(partials.iter()) {
+        assert!(partial.verify(&share.public_key(), msg).is_ok());
+        assert!(partial.verify(&share.public_key(), b"other").is_err());
+    }
+
+    // Any 3 of the partial signatures combine into the same signature.
+    let sig = PartialSignature::combine(&partials[..3]).expect("distinct");
+    assert_eq!(PartialSignature::combine(&partials[2..]), Ok(sig));
+    assert!(pk.verify_threshold(&sig, msg).is_ok());
+    assert!(pk.verify_threshold(&sig, b"other").is_err());
+
+    // The messages aren't hashed with the default hash.
+    assert!(pk.verify(&sig, msg).is_err());
+
+    // Two partial signatures are not enough.
+    let sig = PartialSignature::combine(&partials[..2]).expect("distinct");
+    assert!(pk.verify_threshold(&sig, msg).is_err());
+}
+
+#[test]
+fn threshold_verify_invalid_key() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let shares = sk.split(rng, 1, 1).expect("valid threshold");
+    let sig = PartialSignature::combine(&[shares[0].sign_partial(b"msg")])
+        .expect("distinct");
+
+    assert!(PublicKey::default().verify_threshold(&sig, b"msg").is_err());
+}
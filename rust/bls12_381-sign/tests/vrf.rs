@@ -0,0 +1,72 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "hash-to-curve")]
+
+use dusk_bls12_381_sign::{PublicKey, SecretKey, VrfProof};
+use dusk_bytes::Serializable;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn vrf_prove_verify() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"round 42";
+
+    let (output, proof) = sk.vrf_prove(msg);
+    assert_eq!(pk.vrf_verify(msg, &proof), Ok(output));
+
+    // The output is deterministic.
+    assert_eq!(sk.vrf_prove(msg), (output, proof));
+
+    // Different inputs give different outputs.
+    let (other_output, _) = sk.vrf_prove(b"round 43");
+    assert_ne!(output, other_output);
+
+    // Round trip the proof through its serialization.
+    let proof = VrfProof::from_bytes(&proof.to_bytes()).expect("valid proof");
+    assert_eq!(pk.vrf_verify(msg, &proof), Ok(output));
+}
+
+#[test]
+fn vrf_verify_invalid() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"round 42";
+
+    let (_, proof) = sk.vrf_prove(msg);
+
+    // Different input.
+    assert!(pk.vrf_verify(b"round 43", &proof).is_err());
+
+    // Different key.
+    let other_pk = PublicKey::from(&SecretKey::random(rng));
+    assert!(other_pk.vrf_verify(msg, &proof).is_err());
+
+    // The identity key is rejected.
+    let (_, identity_proof) = SecretKey::default().vrf_prove(msg);
+    assert!(PublicKey::default()
+        .vrf_verify(msg, &identity_proof)
+        .is_err());
+}
+
+#[test]
+fn vrf_proof_is_not_a_signature() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let msg = b"round 42";
+
+    let (_, proof) = sk.vrf_prove(msg);
+    let sig = sk.sign_vulnerable(msg);
+
+    assert_ne!(proof.to_bytes(), sig.to_bytes());
+}
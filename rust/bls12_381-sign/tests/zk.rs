@@ -0,0 +1,117 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "zk")]
+
+use dusk_bls12_381::{G1Affine, G2Affine};
+use dusk_bls12_381_sign::zk::hash_to_scalar;
+use dusk_bls12_381_sign::{
+    MessageHasher, Poseidon, PublicKey, SecretKey, Signature, APK,
+};
+use dusk_bytes::Serializable;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+#[test]
+fn witness_matches_native_verifier() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    for len in [0, 1, 31, 32, 93, 100, 124] {
+        let sk = SecretKey::random(rng);
+        let pk = PublicKey::from(&sk);
+        let apk = APK::from_with::<Poseidon>(&pk);
+
+        let mut msg = vec![0u8; len];
+        rng.fill_bytes(&mut msg);
+
+        let sig = sk.sign_with::<Poseidon>(&pk, &msg);
+        let witness = apk.verify_witness(&sig, &msg);
+        assert!(witness.is_valid());
+        assert!(apk.verify_with::<Poseidon>(&sig, &msg).is_ok());
+
+        assert_eq!(witness.pk.to_bytes(), apk.to_bytes());
+        assert_eq!(witness.msg_hash, hash_to_scalar(&msg));
+        assert_eq!(witness.msg_scalars, Poseidon::input_scalars(&msg));
+        assert_eq!(
+            witness.h0m,
+            G1Affine::from(G1Affine::generator() * witness.msg_hash)
+        );
+
+        let mut hasher = MessageHasher::<Poseidon>::default();
+        hasher.update(&msg);
+        assert!(apk.verify_prehashed(&sig, &hasher.finalize()).is_ok());
+
+        let sig = sk.sign_vulnerable_with::<Poseidon>(&msg);
+        let witness = pk.verify_witness(&sig, &msg);
+        assert!(witness.is_valid());
+        assert!(pk.verify_with::<Poseidon>(&sig, &msg).is_ok());
+    }
+}
+
+/// Public key of the known signature.
+const KNOWN_PK: &str = concat!(
+    "915375db81493926c1a14d5564d200ec53890beb1ec2b74b6dc48a3b830a7a8e",
+    "460d340ce14d8d4e02631ef13a3c957d0d4e6c386e64469a8cf882f583c08e41",
+    "45d832d1d439a02fcd5e3cfdd440f345c6641204a3f6464e1c394b2f9479723d",
+);
+/// Message of the known signature.
+const KNOWN_MSG: &[u8] = b"transfer 100 DUSK to 0x0123";
+/// Signature over the known message, hashed with Poseidon.
+const KNOWN_SIG: &str = concat!(
+    "8177c56e2618a1a062efd8b58fe89114d099b274821651a8211e260b020499d0",
+    "bb4de472e9081ffa204bccf92f1ee2d5",
+);
+/// Poseidon hash of the known message.
+const KNOWN_MSG_HASH: &str =
+    "a1b2afc936cf2d23ebdb7abd9dd34f215b225333df6250c3bf96e8d551120e19";
+/// The known message hashed into G1.
+const KNOWN_H0M: &str = concat!(
+    "a4feb63fcf17549290ef238942477db174d1ceab1489a47986f15a2f4c68b5e7",
+    "39bf942cfb58961088350851d092df2d",
+);
+
+#[test]
+fn witness_of_known_signature() {
+    let pk = hex::decode(KNOWN_PK).expect("valid hex");
+    let pk = pk.try_into().expect("public key size");
+    let pk = PublicKey::from_bytes(&pk).expect("valid public key");
+    let sig = hex::decode(KNOWN_SIG).expect("valid hex");
+    let sig = sig.try_into().expect("signature size");
+    let sig = Signature::from_bytes(&sig).expect("valid signature");
+
+    let witness = pk.verify_witness(&sig, KNOWN_MSG);
+    assert!(witness.is_valid());
+    assert!(pk.verify_with::<Poseidon>(&sig, KNOWN_MSG).is_ok());
+
+    assert_eq!(hex::encode(witness.msg_hash.to_bytes()), KNOWN_MSG_HASH);
+    assert_eq!(hex::encode(witness.h0m.to_bytes()), KNOWN_H0M);
+}
+
+#[test]
+fn witness_of_invalid_signature() {
+    let rng = &mut StdRng::seed_from_u64(0xbeef);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"transfer";
+
+    // Signed with another message, hash or key.
+    let sigs = [
+        sk.sign_vulnerable_with::<Poseidon>(b"transfer!"),
+        sk.sign_vulnerable(msg),
+        SecretKey::random(rng).sign_vulnerable_with::<Poseidon>(msg),
+    ];
+
+    for sig in sigs {
+        let witness = pk.verify_witness(&sig, msg);
+        assert!(!witness.is_valid());
+        assert!(pk.verify_with::<Poseidon>(&sig, msg).is_err());
+        assert_eq!(
+            witness.lhs,
+            dusk_bls12_381::pairing(&witness.sig, &G2Affine::generator())
+        );
+    }
+}
@@ -1,4 +1,17 @@
+use std::{env, path::PathBuf};
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    tonic_build::compile_protos("../../schema/bls12381sig.proto")?;
+    // The descriptors of the protos are served by the reflection service
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+    tonic_build::configure()
+        .file_descriptor_set_path(out_dir.join("bls12381svc_descriptor.bin"))
+        .compile(
+            &[
+                "../../schema/bls12381sig.proto",
+                "../../schema/grpc/health/v1/health.proto",
+                "../../schema/grpc/reflection/v1alpha/reflection.proto",
+            ],
+            &["../../schema"],
+        )?;
     Ok(())
 }
@@ -0,0 +1,184 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use {
+    crate::unix::UnixStreamConnectInfo,
+    std::fmt,
+    std::sync::{Arc, PoisonError, RwLock},
+    tokio::net::unix::UCred,
+    tonic::{service::Interceptor, Request, Status},
+};
+
+/// RPCs of the signer service, as named in the access control list.
+pub const RPCS: &[&str] = &[
+    "GenerateKeys",
+    "Sign",
+    "SignWithKeyId",
+    "ListKeys",
+    "Verify",
+    "BatchVerify",
+    "BatchSign",
+    "VerifyStream",
+    "CreateAPK",
+    "AggregatePK",
+    "AggregateSig",
+];
+
+/// Rule of the access control list, granting the clients it matches the RPCs
+/// and keys it lists.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    /// User ID of the clients, any if unset
+    pub uid: Option<u32>,
+    /// Group ID of the clients, any if unset
+    pub gid: Option<u32>,
+    /// RPCs the clients may call, any if unset
+    pub rpcs: Option<Vec<String>>,
+    /// Public keys of the keystore the clients may sign with, any if unset
+    pub keys: Option<Vec<Vec<u8>>>,
+}
+
+impl Rule {
+    fn matches(&self, peer: Option<&UCred>) -> bool {
+        match peer {
+            Some(peer) => {
+                self.uid.iter().all(|&uid| uid == peer.uid())
+                    && self.gid.iter().all(|&gid| gid == peer.gid())
+            }
+            // clients without credentials only match the rules of anyone
+            None => self.uid.is_none() && self.gid.is_none(),
+        }
+    }
+
+    fn allows_rpc(&self, rpc: &str) -> bool {
+        self.rpcs.iter().all(|rpcs| rpcs.iter().any(|r| r == rpc))
+    }
+}
+
+/// Access control list of the clients, identified by the credentials of their
+/// process on the Unix domain socket. Clients of the TCP listener have no
+/// credentials, and only match the rules of any client. Each call is decided
+/// by the first rule matching its client, and denied if none matches. An
+/// empty list allows every call.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Acl {
+    rules: Vec<Rule>,
+}
+
+impl Acl {
+    pub fn new(rules: Vec<Rule>) -> Self {
+        Self { rules }
+    }
+
+    fn rule(&self, peer: Option<&UCred>) -> Option<&Rule> {
+        self.rules.iter().find(|rule| rule.matches(peer))
+    }
+}
+
+/// Name of the RPC of a request, attached by [`tag_rpc`] for the
+/// [`AclInterceptor`], which can't see the path of the request.
+#[derive(Debug, Clone)]
+pub struct Rpc(String);
+
+/// Attach the name of the RPC to a request.
+pub fn tag_rpc(
+    mut request: hyper::Request<hyper::Body>,
+) -> hyper::Request<hyper::Body> {
+    let rpc = request.uri().path().rsplit('/').next().unwrap_or_default();
+    let rpc = Rpc(rpc.to_owned());
+    request.extensions_mut().insert(rpc);
+    request
+}
+
+/// Keys a call is allowed to sign with, attached to the requests allowed by
+/// the [`AclInterceptor`].
+#[derive(Debug, Clone)]
+pub struct Grant(Option<Vec<Vec<u8>>>);
+
+impl Grant {
+    /// Whether the call may sign with the key identified by its public key.
+    pub fn allows_key(&self, key: &[u8]) -> bool {
+        self.0.iter().all(|keys| keys.iter().any(|k| k == key))
+    }
+}
+
+/// Client of a call, as written in the audit log.
+pub struct Client<'a>(Option<&'a UCred>);
+
+impl<'a, T> From<&'a Request<T>> for Client<'a> {
+    fn from(request: &'a Request<T>) -> Self {
+        Self(peer_cred(request))
+    }
+}
+
+impl fmt::Display for Client<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(cred) => {
+                write!(f, "uid={} gid={}", cred.uid(), cred.gid())?;
+                match cred.pid() {
+                    Some(pid) => write!(f, " pid={}", pid),
+                    None => Ok(()),
+                }
+            }
+            None => write!(f, "unknown client"),
+        }
+    }
+}
+
+fn peer_cred<T>(request: &Request<T>) -> Option<&UCred> {
+    request
+        .extensions()
+        .get::<UnixStreamConnectInfo>()
+        .and_then(|info| info.peer_cred.as_ref())
+}
+
+/// Write a denied call to the audit log.
+pub fn audit_denied(rpc: &str, client: Client, reason: &str) {
+    println!("audit: denied {} to {}: {}", rpc, client, reason);
+}
+
+/// Interceptor of the signer service enforcing an [`Acl`], which may be
+/// swapped while serving.
+#[derive(Clone)]
+pub struct AclInterceptor(Arc<RwLock<Acl>>);
+
+impl AclInterceptor {
+    pub fn new(acl: Arc<RwLock<Acl>>) -> Self {
+        Self(acl)
+    }
+}
+
+impl Interceptor for AclInterceptor {
+    fn call(
+        &mut self,
+        mut request: Request<()>,
+    ) -> Result<Request<()>, Status> {
+        let acl = self.0.read().unwrap_or_else(PoisonError::into_inner);
+        if acl.rules.is_empty() {
+            return Ok(request);
+        }
+
+        let rpc = match request.extensions().get::<Rpc>() {
+            Some(Rpc(rpc)) => rpc.clone(),
+            None => String::new(),
+        };
+        let grant = match acl.rule(peer_cred(&request)) {
+            Some(rule) if rule.allows_rpc(&rpc) => Grant(rule.keys.clone()),
+            Some(_) => {
+                audit_denied(&rpc, Client::from(&request), "RPC not allowed");
+                return Err(Status::permission_denied("RPC not allowed"));
+            }
+            None => {
+                audit_denied(&rpc, Client::from(&request), "unknown client");
+                return Err(Status::permission_denied("unknown client"));
+            }
+        };
+
+        request.extensions_mut().insert(grant);
+        Ok(request)
+    }
+}
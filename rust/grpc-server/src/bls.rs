@@ -0,0 +1,489 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use {
+    clap::{ArgGroup, Parser, Subcommand, ValueEnum},
+    dusk_bls12_381::G2Affine,
+    dusk_bls12_381_sign::{
+        Error as BlsError, PublicKey, SecretKey, Signature, APK,
+    },
+    dusk_bls12_381_sign_ipc::cli::{
+        print_error, read_value, CliError, Encoding, Format, Output, Value,
+    },
+    dusk_bls12_381_sign_ipc::keystore::{read_key, Keystore, KEY_EXTENSION},
+    dusk_bytes::Serializable,
+    std::convert::TryInto,
+    std::ffi::OsStr,
+    std::fs,
+    std::io::Write,
+    std::path::{Path, PathBuf},
+    std::process::exit,
+};
+
+/// Command line arguments of the tool.
+#[derive(Debug, Parser)]
+#[clap(
+    name = "bls",
+    version,
+    about = "Generate keys, sign, aggregate and verify messages using BLS12-381 elliptic curves, without a service",
+    long_about = None,
+    after_help = "Binary values are given with the encoding of --encoding, or as @PATH to read \
+                  the raw bytes of a file, or as - to read the raw bytes of the standard input."
+)]
+struct Args {
+    #[clap(flatten)]
+    format: Format,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Generate a new key pair
+    Gen,
+    /// Derive the public key of a secret key
+    PublicKey { secret_key: String },
+    /// Sign a message with a secret key
+    #[clap(group(
+        ArgGroup::new("key")
+            .required(true)
+            .args(&["secret-key", "key-file", "keystore"])
+    ))]
+    Sign {
+        /// Secret key to sign with
+        #[clap(long)]
+        secret_key: Option<String>,
+        /// File holding the hex encoded secret key to sign with, as in a
+        /// keystore
+        #[clap(long)]
+        key_file: Option<PathBuf>,
+        /// Keystore directory holding the secret key of --public-key
+        #[clap(long, requires = "public-key")]
+        keystore: Option<PathBuf>,
+        /// Public key of the key pair of the keystore to sign with
+        #[clap(long)]
+        public_key: Option<String>,
+        message: String,
+    },
+    /// Verify a signature of a message with an aggregated public key
+    Verify {
+        apk: String,
+        signature: String,
+        message: String,
+    },
+    /// Convert a public key into an aggregated public key
+    CreateApk { public_key: String },
+    /// Add public keys to an aggregated public key
+    AggregatePk {
+        apk: String,
+        #[clap(required = true)]
+        public_keys: Vec<String>,
+    },
+    /// Aggregate signatures into a signature
+    AggregateSig {
+        signature: String,
+        #[clap(required = true)]
+        signatures: Vec<String>,
+    },
+    /// Convert a key into its raw, uncompressed, bytes
+    ToRaw {
+        #[clap(value_enum)]
+        kind: Kind,
+        value: String,
+    },
+    /// Convert raw bytes back into a key, checking it is a valid point
+    FromRaw {
+        #[clap(value_enum)]
+        kind: Kind,
+        value: String,
+    },
+    /// Manage a keystore directory of the service
+    #[clap(subcommand)]
+    Keystore(KeystoreCommand),
+}
+
+#[derive(Debug, Subcommand)]
+enum KeystoreCommand {
+    /// List the public keys of a keystore
+    List { dir: PathBuf },
+    /// Add a secret key to a keystore, generating a new one if none is given
+    Add {
+        dir: PathBuf,
+        #[clap(long)]
+        secret_key: Option<String>,
+    },
+    /// Remove the key pair of a public key from a keystore
+    Remove { dir: PathBuf, public_key: String },
+}
+
+/// Keys with a raw representation.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Kind {
+    PublicKey,
+    Apk,
+}
+
+/// Deserialize a value, naming it in the error.
+fn decode<T: Serializable<N>, const N: usize>(
+    bytes: &[u8],
+    name: &str,
+) -> Result<T, CliError> {
+    let bytes: &[u8; N] = bytes.try_into().map_err(|_| {
+        CliError::Failed(format!(
+            "invalid {}: expected {} bytes, got {}",
+            name,
+            N,
+            bytes.len()
+        ))
+    })?;
+
+    T::from_bytes(bytes)
+        .map_err(|_| CliError::Failed(format!("invalid {}", name)))
+}
+
+/// Name of the file of a key pair in a keystore directory.
+fn key_path(dir: &Path, pk: &PublicKey) -> PathBuf {
+    let name = hex::encode(&pk.to_bytes()[..8]);
+    dir.join(name).with_extension(KEY_EXTENSION)
+}
+
+/// Write a secret key to a new file of a keystore directory, readable by
+/// its owner only.
+fn write_key(dir: &Path, sk: &SecretKey) -> Result<PathBuf, CliError> {
+    let path = key_path(dir, &PublicKey::from(sk));
+    let write_err = |e| {
+        CliError::Failed(format!("unable to write {}: {}", path.display(), e))
+    };
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(&path).map_err(write_err)?;
+    writeln!(file, "{}", hex::encode(sk.to_bytes())).map_err(write_err)?;
+
+    Ok(path)
+}
+
+fn load_keystore(dir: &Path) -> Result<Keystore, CliError> {
+    Keystore::load(dir)
+        .map_err(|e| CliError::Read(dir.display().to_string(), e))
+}
+
+fn run_keystore(
+    command: &KeystoreCommand,
+    encoding: Encoding,
+) -> Result<Output, CliError> {
+    match command {
+        KeystoreCommand::List { dir } => {
+            let public_keys = load_keystore(dir)?
+                .public_keys()
+                .map(|pk| pk.to_bytes().to_vec())
+                .collect();
+            Ok(Output::default().list("public_keys", public_keys))
+        }
+        KeystoreCommand::Add { dir, secret_key } => {
+            let sk = match secret_key {
+                Some(sk) => decode(&read_value(sk, encoding)?, "secret key")?,
+                None => SecretKey::random(&mut rand_core::OsRng),
+            };
+            write_key(dir, &sk)?;
+            Ok(Output::default()
+                .bytes("public_key", PublicKey::from(&sk).to_bytes().to_vec()))
+        }
+        KeystoreCommand::Remove { dir, public_key } => {
+            let pk: PublicKey =
+                decode(&read_value(public_key, encoding)?, "public key")?;
+            let read_err = |e| CliError::Read(dir.display().to_string(), e);
+
+            // the key pair may have been added under any file name
+            let mut removed = false;
+            for entry in fs::read_dir(dir).map_err(read_err)? {
+                let path = entry.map_err(read_err)?.path();
+                if path.extension() != Some(OsStr::new(KEY_EXTENSION)) {
+                    continue;
+                }
+                let sk = read_key(&path).map_err(|e| {
+                    CliError::Read(path.display().to_string(), e)
+                })?;
+                if PublicKey::from(&sk) == pk {
+                    fs::remove_file(&path).map_err(|e| {
+                        CliError::Failed(format!(
+                            "unable to remove {}: {}",
+                            path.display(),
+                            e
+                        ))
+                    })?;
+                    removed = true;
+                }
+            }
+
+            if !removed {
+                return Err(CliError::Failed("unknown public key".into()));
+            }
+            Ok(Output::default().bytes("public_key", pk.to_bytes().to_vec()))
+        }
+    }
+}
+
+/// Run a command locally.
+fn run(command: &Command, encoding: Encoding) -> Result<Output, CliError> {
+    let value = |arg: &str| read_value(arg, encoding);
+
+    match command {
+        Command::Gen => {
+            let sk = SecretKey::random(&mut rand_core::OsRng);
+            Ok(Output::default()
+                .bytes("secret_key", sk.to_bytes().to_vec())
+                .bytes("public_key", PublicKey::from(&sk).to_bytes().to_vec()))
+        }
+        Command::PublicKey { secret_key } => {
+            let sk: SecretKey = decode(&value(secret_key)?, "secret key")?;
+            Ok(Output::default()
+                .bytes("public_key", PublicKey::from(&sk).to_bytes().to_vec()))
+        }
+        Command::Sign {
+            secret_key,
+            key_file,
+            keystore,
+            public_key,
+            message,
+        } => {
+            let (sk, pk) = match (secret_key, key_file, keystore, public_key) {
+                (Some(sk), ..) => {
+                    let sk: SecretKey = decode(&value(sk)?, "secret key")?;
+                    (sk, PublicKey::from(&sk))
+                }
+                (_, Some(path), ..) => {
+                    let sk = read_key(path).map_err(|e| {
+                        CliError::Read(path.display().to_string(), e)
+                    })?;
+                    (sk, PublicKey::from(&sk))
+                }
+                (_, _, Some(dir), Some(pk)) => {
+                    *load_keystore(dir)?.get(&value(pk)?).ok_or_else(|| {
+                        CliError::Failed("unknown public key".into())
+                    })?
+                }
+                // enforced by the arguments parser
+                _ => unreachable!("no secret key given"),
+            };
+
+            let sig = sk.sign(&pk, &value(message)?);
+            Ok(Output::default().bytes("signature", sig.to_bytes().to_vec()))
+        }
+        Command::Verify {
+            apk,
+            signature,
+            message,
+        } => {
+            let apk: APK = decode(&value(apk)?, "aggregated public key")?;
+            let sig: Signature = decode(&value(signature)?, "signature")?;
+
+            match apk.verify(&sig, &value(message)?) {
+                Ok(()) => Ok(Output::default().bool("valid", true)),
+                Err(BlsError::InvalidSignature) => {
+                    Ok(Output::default().bool("valid", false))
+                }
+                Err(e) => Err(e.into()),
+            }
+        }
+        Command::CreateApk { public_key } => {
+            let pk: PublicKey = decode(&value(public_key)?, "public key")?;
+            Ok(Output::default()
+                .bytes("apk", APK::from(&pk).to_bytes().to_vec()))
+        }
+        Command::AggregatePk { apk, public_keys } => {
+            let mut apk: APK = decode(&value(apk)?, "aggregated public key")?;
+            let pks = public_keys
+                .iter()
+                .map(|pk| decode(&value(pk)?, "public key"))
+                .collect::<Result<Vec<PublicKey>, _>>()?;
+
+            apk.aggregate(&pks);
+            Ok(Output::default().bytes("apk", apk.to_bytes().to_vec()))
+        }
+        Command::AggregateSig {
+            signature,
+            signatures,
+        } => {
+            let sig: Signature = decode(&value(signature)?, "signature")?;
+            let sigs = signatures
+                .iter()
+                .map(|sig| decode(&value(sig)?, "signature"))
+                .collect::<Result<Vec<Signature>, _>>()?;
+
+            Ok(Output::default()
+                .bytes("signature", sig.aggregate(&sigs).to_bytes().to_vec()))
+        }
+        Command::ToRaw { kind, value: arg } => {
+            let bytes = value(arg)?;
+            let raw = match kind {
+                Kind::PublicKey => {
+                    decode::<PublicKey, _>(&bytes, "public key")?.to_raw_bytes()
+                }
+                Kind::Apk => decode::<APK, _>(&bytes, "aggregated public key")?
+                    .to_raw_bytes(),
+            };
+            Ok(Output::default().bytes("raw", raw.to_vec()))
+        }
+        Command::FromRaw { kind, value: arg } => {
+            let raw = value(arg)?;
+            if raw.len() != G2Affine::RAW_SIZE {
+                return Err(CliError::Failed(format!(
+                    "invalid raw bytes: expected {} bytes, got {}",
+                    G2Affine::RAW_SIZE,
+                    raw.len()
+                )));
+            }
+
+            // SAFETY: the length is checked above, and both keys have the
+            // raw representation of their point, checked right after
+            let pk = unsafe { PublicKey::from_slice_unchecked(&raw) };
+            if !pk.is_valid() {
+                return Err(CliError::Failed("invalid raw bytes".into()));
+            }
+
+            let (name, bytes) = match kind {
+                Kind::PublicKey => ("public_key", pk.to_bytes()),
+                Kind::Apk => (
+                    "apk",
+                    unsafe { APK::from_slice_unchecked(&raw) }.to_bytes(),
+                ),
+            };
+
+            Ok(Output::default().bytes(name, bytes.to_vec()))
+        }
+        Command::Keystore(command) => run_keystore(command, encoding),
+    }
+}
+
+fn main() {
+    let args = Args::parse();
+
+    match run(&args.command, args.format.encoding) {
+        Ok(output) => {
+            output.print(&args.format);
+            // let scripts check a verification by the exit code
+            if let Some(Value::Bool(false)) = output.get("valid") {
+                exit(1);
+            }
+        }
+        Err(e) => {
+            print_error(&e, &args.format);
+            exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bytes(output: &Output, name: &str) -> Vec<u8> {
+        match output.get(name) {
+            Some(Value::Bytes(bytes)) => bytes.clone(),
+            other => panic!("expected bytes for {}, got {:?}", name, other),
+        }
+    }
+
+    fn list_keys(dir: &Path) -> Result<Vec<Vec<u8>>, CliError> {
+        let list = KeystoreCommand::List { dir: dir.into() };
+        match run_keystore(&list, Encoding::Hex)?.get("public_keys") {
+            Some(Value::List(keys)) => Ok(keys.clone()),
+            other => panic!("expected a list of keys, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn keystore_round_trip() -> Result<(), CliError> {
+        let dir = std::env::temp_dir()
+            .join(format!("bls-keystore-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir(&dir).expect("keystore directory should be created");
+
+        let add = |secret_key: Option<&SecretKey>| {
+            let secret_key = secret_key.map(|sk| hex::encode(sk.to_bytes()));
+            let dir = dir.clone();
+            run_keystore(
+                &KeystoreCommand::Add { dir, secret_key },
+                Encoding::Hex,
+            )
+        };
+        let remove = |pk: &[u8]| {
+            let public_key = hex::encode(pk);
+            let dir = dir.clone();
+            run_keystore(
+                &KeystoreCommand::Remove { dir, public_key },
+                Encoding::Hex,
+            )
+        };
+
+        let generated = bytes(&add(None)?, "public_key");
+        let sk = SecretKey::random(&mut rand_core::OsRng);
+        let pk = PublicKey::from(&sk).to_bytes().to_vec();
+        assert_eq!(bytes(&add(Some(&sk))?, "public_key"), pk);
+        assert!(add(Some(&sk)).is_err(), "a key is only added once");
+
+        // the keys are written private, or the keystore would fail to load
+        let mut keys = vec![generated.clone(), pk.clone()];
+        keys.sort();
+        assert_eq!(list_keys(&dir)?, keys);
+
+        let sign = Command::Sign {
+            secret_key: None,
+            key_file: None,
+            keystore: Some(dir.clone()),
+            public_key: Some(hex::encode(&pk)),
+            message: hex::encode(b"hello"),
+        };
+        let sig = bytes(&run(&sign, Encoding::Hex)?, "signature");
+        let expected = sk.sign(&PublicKey::from(&sk), b"hello");
+        assert_eq!(sig, expected.to_bytes().to_vec());
+
+        assert_eq!(bytes(&remove(&pk)?, "public_key"), pk);
+        assert_eq!(list_keys(&dir)?, vec![generated.clone()]);
+        assert!(remove(&pk).is_err(), "the key is already removed");
+
+        remove(&generated)?;
+        assert!(list_keys(&dir)?.is_empty());
+
+        fs::remove_dir_all(&dir).expect("keystore directory should be removed");
+        Ok(())
+    }
+
+    #[test]
+    fn from_raw_rejects_invalid_points() -> Result<(), CliError> {
+        let pk = PublicKey::from(&SecretKey::random(&mut rand_core::OsRng));
+        let from_raw = |kind, raw: &[u8]| {
+            let value = hex::encode(raw);
+            run(&Command::FromRaw { kind, value }, Encoding::Hex)
+        };
+
+        let raw = pk.to_raw_bytes();
+        let res = from_raw(Kind::PublicKey, &raw)?;
+        assert_eq!(bytes(&res, "public_key"), pk.to_bytes().to_vec());
+        let apk = APK::from(&pk);
+        let res = from_raw(Kind::Apk, &apk.to_raw_bytes())?;
+        assert_eq!(bytes(&res, "apk"), apk.to_bytes().to_vec());
+
+        // a point off the curve
+        let mut off_curve = raw;
+        off_curve[G2Affine::RAW_SIZE / 2] ^= 1;
+        // the identity, which is a point of the group but not a valid key
+        let identity = PublicKey::default().to_raw_bytes();
+
+        for raw in [&off_curve[..], &identity[..], &raw[1..]] {
+            for kind in [Kind::PublicKey, Kind::Apk] {
+                assert!(from_raw(kind, raw).is_err());
+            }
+        }
+
+        Ok(())
+    }
+}
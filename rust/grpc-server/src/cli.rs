@@ -0,0 +1,185 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Input and output of the command line tools.
+
+use {
+    clap::{Args, ValueEnum},
+    dusk_bls12_381_sign::Error as BlsError,
+    serde_json::{Map, Value as Json},
+    std::fs,
+    std::io::{self, Read},
+    thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error("invalid hex: {0}")]
+    Hex(#[from] hex::FromHexError),
+    #[error("invalid base64: {0}")]
+    Base64(#[from] base64::DecodeError),
+    #[error("unable to read {0}: {1}")]
+    Read(String, io::Error),
+    #[error("{0}")]
+    Failed(String),
+    #[error("{0}")]
+    Status(Box<tonic::Status>),
+    #[error("unable to connect to {0}: {1}")]
+    Connect(String, String),
+}
+
+impl From<tonic::Status> for CliError {
+    fn from(status: tonic::Status) -> Self {
+        Self::Status(Box::new(status))
+    }
+}
+
+impl From<BlsError> for CliError {
+    fn from(err: BlsError) -> Self {
+        CliError::Failed(err.to_string())
+    }
+}
+
+/// Encoding of binary values on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Encoding {
+    Hex,
+    Base64,
+}
+
+impl Encoding {
+    pub fn encode(self, bytes: &[u8]) -> String {
+        match self {
+            Encoding::Hex => hex::encode(bytes),
+            Encoding::Base64 => base64::encode(bytes),
+        }
+    }
+
+    pub fn decode(self, s: &str) -> Result<Vec<u8>, CliError> {
+        let s = s.trim();
+        Ok(match self {
+            Encoding::Hex => hex::decode(s.trim_start_matches("0x"))?,
+            Encoding::Base64 => base64::decode(s)?,
+        })
+    }
+}
+
+/// Options of the input and output of the command line tools.
+#[derive(Debug, Args)]
+pub struct Format {
+    /// Encoding of the binary values given as arguments and printed
+    #[clap(long, value_enum, default_value = "hex", global = true)]
+    pub encoding: Encoding,
+
+    /// Print the output as a JSON object
+    #[clap(long, global = true)]
+    pub json: bool,
+}
+
+/// Read a binary value given as an argument: `@PATH` reads the raw bytes of
+/// a file, `-` reads the raw bytes of the standard input, and anything else
+/// is decoded with `encoding`.
+pub fn read_value(arg: &str, encoding: Encoding) -> Result<Vec<u8>, CliError> {
+    if arg == "-" {
+        let mut bytes = vec![];
+        io::stdin()
+            .read_to_end(&mut bytes)
+            .map_err(|e| CliError::Read("standard input".into(), e))?;
+        Ok(bytes)
+    } else if let Some(path) = arg.strip_prefix('@') {
+        fs::read(path).map_err(|e| CliError::Read(path.into(), e))
+    } else {
+        encoding.decode(arg)
+    }
+}
+
+/// A field of the output of a command.
+#[derive(Debug)]
+pub enum Value {
+    Bytes(Vec<u8>),
+    Bool(bool),
+    List(Vec<Vec<u8>>),
+}
+
+/// Output of a command, as a list of named fields.
+#[derive(Debug, Default)]
+pub struct Output(Vec<(&'static str, Value)>);
+
+impl Output {
+    pub fn bytes(mut self, name: &'static str, bytes: Vec<u8>) -> Self {
+        self.0.push((name, Value::Bytes(bytes)));
+        self
+    }
+
+    pub fn bool(mut self, name: &'static str, b: bool) -> Self {
+        self.0.push((name, Value::Bool(b)));
+        self
+    }
+
+    pub fn list(mut self, name: &'static str, list: Vec<Vec<u8>>) -> Self {
+        self.0.push((name, Value::List(list)));
+        self
+    }
+
+    /// Look up the value of a field.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.0.iter().find(|(n, _)| *n == name).map(|(_, v)| v)
+    }
+
+    /// The output as a JSON object, with the binary values encoded with
+    /// `encoding`.
+    pub fn to_json(&self, encoding: Encoding) -> Json {
+        let mut map = Map::new();
+        for (name, value) in &self.0 {
+            let value = match value {
+                Value::Bytes(bytes) => Json::String(encoding.encode(bytes)),
+                Value::Bool(b) => Json::Bool(*b),
+                Value::List(list) => Json::Array(
+                    list.iter()
+                        .map(|bytes| Json::String(encoding.encode(bytes)))
+                        .collect(),
+                ),
+            };
+            map.insert((*name).into(), value);
+        }
+        Json::Object(map)
+    }
+
+    /// Print the output, either as JSON or as a `name: value` line per field
+    /// and item of a list.
+    pub fn print(&self, format: &Format) {
+        if format.json {
+            println!("{}", self.to_json(format.encoding));
+            return;
+        }
+
+        for (name, value) in &self.0 {
+            match value {
+                Value::Bytes(bytes) => {
+                    println!("{}: {}", name, format.encoding.encode(bytes))
+                }
+                Value::Bool(b) => println!("{}: {}", name, b),
+                // a line per item, so that an empty list prints nothing
+                Value::List(list) => {
+                    for bytes in list {
+                        println!("{}: {}", name, format.encoding.encode(bytes))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Print an error, either as a JSON object or on the standard error.
+pub fn print_error(err: &CliError, format: &Format) {
+    if format.json {
+        let mut map = Map::new();
+        map.insert("error".into(), Json::String(err.to_string()));
+        println!("{}", Json::Object(map));
+    } else {
+        eprintln!("error: {}", err);
+    }
+}
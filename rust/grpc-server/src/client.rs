@@ -6,71 +6,61 @@
 
 #![cfg_attr(not(unix), allow(unused_imports))]
 
-tonic::include_proto!("signer");
-
 use {
-    // clap::App,
-    signer_client::SignerClient,
-    std::convert::TryFrom,
-    tokio::net::UnixStream,
-    tonic::transport::{Endpoint, Uri},
-    tower::service_fn,
+    clap::Parser,
+    dusk_bls12_381_sign_ipc::cli::{print_error, Format, Value},
+    dusk_bls12_381_sign_ipc::remote::{self, Command},
+    std::path::PathBuf,
+    std::process::exit,
 };
 
+/// Default UDS path of the service.
+const SOCKET_PATH: &str = "/tmp/bls12381svc.sock";
+
+/// Command line arguments of the client.
+#[derive(Debug, Parser)]
+#[clap(
+    name = "bls12381cli",
+    version,
+    about = "Generate keys, sign, aggregate and verify messages using BLS12-381 elliptic curves",
+    long_about = None,
+    after_help = "Binary values are given with the encoding of --encoding, or as @PATH to read \
+                  the raw bytes of a file, or as - to read the raw bytes of the standard input."
+)]
+struct Args {
+    /// Path of the Unix domain socket of the service
+    #[clap(long, env = "BLS12381SVC_SOCKET", default_value = SOCKET_PATH)]
+    socket: PathBuf,
+
+    #[clap(flatten)]
+    format: Format,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // // define the command line parameters to call the bls12381svc
-    // let matches = App::new("bls12381cli")
-    //     .version("v0.1.0")
-    //     .author("Loki <david@dusk.network>")
-    //     .about("Generate keys, sign, aggregate and verify messages using
-    // BLS12-381 elliptic curves")     .subcommand(App::new("gen")
-    //         .about("Generate a new BLS12-381 key pair"))
-    //     .subcommand(App::new("sign")
-    //         .about("Sign a message with a given key pair")
-    //         .arg("<secretkey> 'Secret key'")
-    //         .arg("<publickey> 'Public key'")
-    //         .arg("<message> 'Message to be signed'")
-    //     )
-    //     .subcommand(App::new("verify")
-    //         .about("Verify a signature against message and public key")
-    //         .arg("<apk> '(Aggregated) Public key'")
-    //         .arg("<signature> 'Signature'")
-    //         .arg("<message> 'Message bearing signature'")
-    //     )
-    //     .subcommand(App::new("createapk")
-    //         .about("Convert a public key into an aggregated public key")
-    //         .arg("<publickey> 'Public key'")
-    //     )
-    //     .subcommand(App::new("aggregatepk")
-    //         .about("Add more public keys to an aggregated public key")
-    //         .arg("<secretkey> 'Aggregated public key'")
-    //         .arg("<publickey>... 'Public Key'")
-    //     )
-    //     .subcommand(App::new("aggregatesig")
-    //         .about("Aggregate multiple signatures into one signature")
-    //         .arg("<signature> 'signature to aggregate into'")
-    //         .arg("<signatures>... 'signatures to be merged into the first'")
-    //     )
-    //     .get_matches();
-    // println!("{:?}", matches);
+async fn main() {
+    let args = Args::parse();
 
-    // create a channel to connect to the socket of the server
-    let path: &str = "/tmp/bls12381svc.sock";
-    let channel = Endpoint::try_from("http://[::]:50051")
-        .expect("Serde error on addr reading")
-        .connect_with_connector(service_fn(move |_: Uri| {
-            UnixStream::connect(path)
-        }))
-        .await
-        .expect("Error generating a Channel");
+    let res = match remote::connect(args.socket).await {
+        Ok(mut client) => {
+            remote::run(&mut client, &args.command, args.format.encoding).await
+        }
+        Err(e) => Err(e),
+    };
 
-    let mut client = SignerClient::new(channel);
-    // here we are just making a token request and not formatting the output
-    // correctly
-    let request = tonic::Request::new(GenerateKeysRequest {});
-    let response = client.generate_keys(request).await?;
-    println!("Secret key {:?}", response.get_ref().secret_key);
-    println!("Public key {:?}", response.get_ref().public_key);
-    Ok(())
+    match res {
+        Ok(output) => {
+            output.print(&args.format);
+            // let scripts check a verification by the exit code
+            if let Some(Value::Bool(false)) = output.get("valid") {
+                exit(1);
+            }
+        }
+        Err(e) => {
+            print_error(&e, &args.format);
+            exit(1);
+        }
+    }
 }
@@ -0,0 +1,518 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use {
+    crate::acl::{Acl, Rule, RPCS},
+    crate::pool::default_workers,
+    clap::Parser,
+    serde::Deserialize,
+    std::fs,
+    std::net::SocketAddr,
+    std::path::PathBuf,
+    std::time::Duration,
+    thiserror::Error,
+};
+
+/// Default UDS path that Rusk GRPC-server will connect to.
+pub const SOCKET_PATH: &str = "/tmp/bls12381svc.sock";
+
+/// Default limit of the size of the messages of the requests, in bytes.
+pub const MAX_MESSAGE_SIZE: usize = 4 * 1024 * 1024;
+
+/// Default limit of the number of items of the lists of the requests.
+pub const MAX_LIST_LENGTH: usize = 1024;
+
+/// Default limit of the calls in flight on each connection.
+pub const CONCURRENCY_LIMIT: usize = 64;
+
+/// Default limit of the calls in flight across all connections.
+pub const MAX_CALLS: usize = 256;
+
+/// Default time given to the in-flight requests to complete on shutdown, in
+/// seconds.
+pub const SHUTDOWN_TIMEOUT: u64 = 30;
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("unable to read config file {0}: {1}")]
+    Read(PathBuf, std::io::Error),
+    #[error("unable to parse config file {0}: {1}")]
+    Parse(PathBuf, toml::de::Error),
+    #[error("invalid socket mode {0}, expected an octal number")]
+    SocketMode(String),
+    #[error("the TCP listener requires a TLS certificate, key and client CA")]
+    MissingTls,
+    #[error("unknown RPC {0} in the access control list")]
+    UnknownRpc(String),
+    #[error("invalid hex key {0} in the access control list")]
+    InvalidKey(String),
+    #[error("{0} must be at least 1")]
+    Zero(&'static str),
+}
+
+/// Command line arguments of the service. Every argument can also be set
+/// through its environment variable or in the config file, in decreasing
+/// order of precedence.
+#[derive(Debug, Parser)]
+#[clap(
+    name = "bls12381svc",
+    version,
+    about = "gRPC service for BLS12-381 signatures",
+    long_about = None
+)]
+struct Args {
+    /// Path of the TOML config file
+    #[clap(long, env = "BLS12381SVC_CONFIG")]
+    config: Option<PathBuf>,
+
+    /// Path of the Unix domain socket to listen on
+    #[clap(long, env = "BLS12381SVC_SOCKET")]
+    socket: Option<PathBuf>,
+
+    /// Path of the PID file locking out other instances, defaults to the
+    /// socket path with a `pid` extension
+    #[clap(long, env = "BLS12381SVC_PID_FILE")]
+    pid_file: Option<PathBuf>,
+
+    /// Permissions of the Unix domain socket, in octal
+    #[clap(long, env = "BLS12381SVC_SOCKET_MODE")]
+    socket_mode: Option<String>,
+
+    /// Directory of the keys to sign with by reference
+    #[clap(long, env = "BLS12381SVC_KEYSTORE")]
+    keystore: Option<PathBuf>,
+
+    /// Address of the TCP listener, disabled if unset
+    #[clap(long, env = "BLS12381SVC_TCP")]
+    tcp: Option<SocketAddr>,
+
+    /// Address of the HTTP listener serving Prometheus metrics at
+    /// `/metrics`, disabled if unset
+    #[clap(long, env = "BLS12381SVC_METRICS")]
+    metrics: Option<SocketAddr>,
+
+    /// Maximum size of the messages of the requests, in bytes
+    #[clap(long, env = "BLS12381SVC_MAX_MESSAGE_SIZE")]
+    max_message_size: Option<usize>,
+
+    /// Maximum number of keys, signatures or items in a request
+    #[clap(long, env = "BLS12381SVC_MAX_LIST_LENGTH")]
+    max_list_length: Option<usize>,
+
+    /// Maximum number of calls in flight on each connection, the others
+    /// waiting for their turn
+    #[clap(long, env = "BLS12381SVC_CONCURRENCY_LIMIT")]
+    concurrency_limit: Option<usize>,
+
+    /// Maximum number of calls in flight across all connections, the others
+    /// waiting for their turn
+    #[clap(long, env = "BLS12381SVC_MAX_CALLS")]
+    max_calls: Option<usize>,
+
+    /// Number of calls doing CPU-bound work at once, defaults to the number
+    /// of CPUs
+    #[clap(long, env = "BLS12381SVC_WORKERS")]
+    workers: Option<usize>,
+
+    /// Seconds given to the in-flight requests to complete on shutdown,
+    /// after which they are dropped
+    #[clap(long, env = "BLS12381SVC_SHUTDOWN_TIMEOUT")]
+    shutdown_timeout: Option<u64>,
+
+    /// PEM certificate chain of the TCP listener
+    #[clap(long, env = "BLS12381SVC_TLS_CERT")]
+    tls_cert: Option<PathBuf>,
+
+    /// PEM private key of the TCP listener
+    #[clap(long, env = "BLS12381SVC_TLS_KEY")]
+    tls_key: Option<PathBuf>,
+
+    /// PEM certificate of the CA authenticating the clients of the TCP
+    /// listener
+    #[clap(long, env = "BLS12381SVC_TLS_CLIENT_CA")]
+    tls_client_ca: Option<PathBuf>,
+}
+
+/// Contents of the config file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct File {
+    socket: Option<PathBuf>,
+    pid_file: Option<PathBuf>,
+    socket_mode: Option<String>,
+    keystore: Option<PathBuf>,
+    tcp: Option<SocketAddr>,
+    metrics: Option<SocketAddr>,
+    max_message_size: Option<usize>,
+    max_list_length: Option<usize>,
+    concurrency_limit: Option<usize>,
+    max_calls: Option<usize>,
+    workers: Option<usize>,
+    shutdown_timeout: Option<u64>,
+    tls: FileTls,
+    acl: Vec<FileRule>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct FileTls {
+    cert: Option<PathBuf>,
+    key: Option<PathBuf>,
+    client_ca: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileRule {
+    uid: Option<u32>,
+    gid: Option<u32>,
+    rpcs: Option<Vec<String>>,
+    keys: Option<Vec<String>>,
+}
+
+impl FileRule {
+    fn into_rule(self) -> Result<Rule, ConfigError> {
+        if let Some(rpc) = self
+            .rpcs
+            .iter()
+            .flatten()
+            .find(|rpc| !RPCS.contains(&rpc.as_str()))
+        {
+            return Err(ConfigError::UnknownRpc(rpc.clone()));
+        }
+
+        let keys = match self.keys {
+            Some(keys) => Some(
+                keys.into_iter()
+                    .map(|key| {
+                        hex::decode(key.trim_start_matches("0x"))
+                            .map_err(|_| ConfigError::InvalidKey(key))
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            None => None,
+        };
+
+        Ok(Rule {
+            uid: self.uid,
+            gid: self.gid,
+            rpcs: self.rpcs,
+            keys,
+        })
+    }
+}
+
+/// Configuration of the service.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// Path of the Unix domain socket
+    pub socket: PathBuf,
+    /// Path of the PID file
+    pub pid_file: PathBuf,
+    /// Permissions of the Unix domain socket, left to the umask if unset
+    pub socket_mode: Option<u32>,
+    /// Directory of the keystore, if any
+    pub keystore: Option<PathBuf>,
+    /// TCP listener, if any
+    pub tcp: Option<TcpConfig>,
+    /// Address of the metrics listener, if any
+    pub metrics: Option<SocketAddr>,
+    /// Access control list of the clients, allowing everything if empty
+    pub acl: Acl,
+    /// Maximum size of the messages of the requests, in bytes
+    pub max_message_size: usize,
+    /// Maximum number of items in the lists of the requests
+    pub max_list_length: usize,
+    /// Maximum number of calls in flight on each connection
+    pub concurrency_limit: usize,
+    /// Maximum number of calls in flight across all connections
+    pub max_calls: usize,
+    /// Number of calls doing CPU-bound work at once
+    pub workers: usize,
+    /// Time given to the in-flight requests to complete on shutdown
+    pub shutdown_timeout: Duration,
+}
+
+/// Configuration of the TCP listener.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TcpConfig {
+    pub addr: SocketAddr,
+    /// PEM certificate chain
+    pub cert: PathBuf,
+    /// PEM private key
+    pub key: PathBuf,
+    /// PEM certificate of the CA authenticating the clients
+    pub client_ca: PathBuf,
+}
+
+impl Config {
+    /// Load the configuration from the command line, the environment and the
+    /// config file.
+    pub fn load() -> Result<Self, ConfigError> {
+        Self::from_args(Args::parse())
+    }
+
+    fn from_args(args: Args) -> Result<Self, ConfigError> {
+        let file = match &args.config {
+            Some(path) => {
+                let toml = fs::read_to_string(path)
+                    .map_err(|e| ConfigError::Read(path.clone(), e))?;
+                toml::from_str(&toml)
+                    .map_err(|e| ConfigError::Parse(path.clone(), e))?
+            }
+            None => File::default(),
+        };
+
+        let socket_mode = args
+            .socket_mode
+            .or(file.socket_mode)
+            .map(parse_socket_mode)
+            .transpose()?;
+
+        let tcp = match args.tcp.or(file.tcp) {
+            Some(addr) => {
+                let cert = args.tls_cert.or(file.tls.cert);
+                let key = args.tls_key.or(file.tls.key);
+                let client_ca = args.tls_client_ca.or(file.tls.client_ca);
+                match (cert, key, client_ca) {
+                    (Some(cert), Some(key), Some(client_ca)) => {
+                        Some(TcpConfig {
+                            addr,
+                            cert,
+                            key,
+                            client_ca,
+                        })
+                    }
+                    _ => return Err(ConfigError::MissingTls),
+                }
+            }
+            None => None,
+        };
+
+        let socket = args
+            .socket
+            .or(file.socket)
+            .unwrap_or_else(|| SOCKET_PATH.into());
+        let pid_file = args
+            .pid_file
+            .or(file.pid_file)
+            .unwrap_or_else(|| socket.with_extension("pid"));
+
+        let concurrency_limit = args
+            .concurrency_limit
+            .or(file.concurrency_limit)
+            .unwrap_or(CONCURRENCY_LIMIT);
+        if concurrency_limit == 0 {
+            return Err(ConfigError::Zero("concurrency_limit"));
+        }
+        let max_calls = args.max_calls.or(file.max_calls).unwrap_or(MAX_CALLS);
+        if max_calls == 0 {
+            return Err(ConfigError::Zero("max_calls"));
+        }
+        let workers = args
+            .workers
+            .or(file.workers)
+            .unwrap_or_else(default_workers);
+        if workers == 0 {
+            return Err(ConfigError::Zero("workers"));
+        }
+
+        let acl = file
+            .acl
+            .into_iter()
+            .map(FileRule::into_rule)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            socket,
+            pid_file,
+            socket_mode,
+            keystore: args.keystore.or(file.keystore),
+            tcp,
+            metrics: args.metrics.or(file.metrics),
+            acl: Acl::new(acl),
+            max_message_size: args
+                .max_message_size
+                .or(file.max_message_size)
+                .unwrap_or(MAX_MESSAGE_SIZE),
+            max_list_length: args
+                .max_list_length
+                .or(file.max_list_length)
+                .unwrap_or(MAX_LIST_LENGTH),
+            concurrency_limit,
+            max_calls,
+            workers,
+            shutdown_timeout: Duration::from_secs(
+                args.shutdown_timeout
+                    .or(file.shutdown_timeout)
+                    .unwrap_or(SHUTDOWN_TIMEOUT),
+            ),
+        })
+    }
+}
+
+/// Parse the permissions of the Unix domain socket, in octal with an optional
+/// `0o` prefix.
+fn parse_socket_mode(mode: String) -> Result<u32, ConfigError> {
+    u32::from_str_radix(mode.trim_start_matches("0o"), 8)
+        .map_err(|_| ConfigError::SocketMode(mode))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parse the command line `args`, with the config file `toml` if any.
+    fn config(
+        name: &str,
+        toml: Option<&str>,
+        args: &[&str],
+    ) -> Result<Config, ConfigError> {
+        let path = std::env::temp_dir().join(format!(
+            "bls12381svc-config-{}-{}.toml",
+            name,
+            std::process::id()
+        ));
+
+        let mut argv = vec!["bls12381svc".to_string()];
+        if let Some(toml) = toml {
+            fs::write(&path, toml).expect("config file written");
+            argv.push("--config".into());
+            argv.push(path.display().to_string());
+        }
+        argv.extend(args.iter().map(|arg| arg.to_string()));
+
+        let config = Config::from_args(
+            Args::try_parse_from(argv).expect("valid arguments"),
+        );
+        let _ = fs::remove_file(&path);
+        config
+    }
+
+    #[test]
+    fn defaults() {
+        let config = config("defaults", None, &[]).expect("valid config");
+
+        assert_eq!(config.socket, PathBuf::from(SOCKET_PATH));
+        assert_eq!(
+            config.pid_file,
+            PathBuf::from(SOCKET_PATH).with_extension("pid")
+        );
+        assert_eq!(config.socket_mode, None);
+        assert_eq!(config.tcp, None);
+        assert_eq!(config.max_list_length, MAX_LIST_LENGTH);
+        assert_eq!(config.concurrency_limit, CONCURRENCY_LIMIT);
+    }
+
+    #[test]
+    fn precedence() {
+        // Only this test sets the variable, and no other test checks the
+        // option.
+        const VAR: &str = "BLS12381SVC_MAX_MESSAGE_SIZE";
+        let toml = "max_message_size = 100\nmax_list_length = 10";
+
+        let file =
+            config("precedence-file", Some(toml), &[]).expect("valid config");
+        assert_eq!(file.max_message_size, 100);
+        assert_eq!(file.max_list_length, 10);
+
+        std::env::set_var(VAR, "200");
+        let env = config("precedence-env", Some(toml), &[]);
+        let cli = config(
+            "precedence-cli",
+            Some(toml),
+            &["--max-message-size", "300"],
+        );
+        std::env::remove_var(VAR);
+
+        let env = env.expect("valid config");
+        assert_eq!(env.max_message_size, 200);
+        assert_eq!(env.max_list_length, 10);
+
+        let cli = cli.expect("valid config");
+        assert_eq!(cli.max_message_size, 300);
+        assert_eq!(cli.max_list_length, 10);
+    }
+
+    #[test]
+    fn socket_mode_is_octal() {
+        let mode = |toml: Option<&str>, args: &[&str]| {
+            config("socket-mode", toml, args).map(|config| config.socket_mode)
+        };
+
+        assert_eq!(mode(None, &["--socket-mode", "660"]).unwrap(), Some(0o660));
+        assert_eq!(
+            mode(None, &["--socket-mode", "0o600"]).unwrap(),
+            Some(0o600)
+        );
+        assert_eq!(
+            mode(Some("socket_mode = \"660\""), &[]).unwrap(),
+            Some(0o660)
+        );
+        assert_eq!(
+            mode(Some("socket_mode = \"640\""), &["--socket-mode", "600"])
+                .unwrap(),
+            Some(0o600)
+        );
+
+        for invalid in ["680", "rw", ""] {
+            assert!(matches!(
+                mode(None, &["--socket-mode", invalid]),
+                Err(ConfigError::SocketMode(mode)) if mode == invalid
+            ));
+        }
+        assert!(matches!(
+            mode(Some("socket_mode = \"0x1b0\""), &[]),
+            Err(ConfigError::SocketMode(_))
+        ));
+
+        // A number would be read as decimal, so the file takes a string.
+        assert!(matches!(
+            mode(Some("socket_mode = 660"), &[]),
+            Err(ConfigError::Parse(..))
+        ));
+    }
+
+    #[test]
+    fn tcp_requires_tls() {
+        let tls = ["--tls-cert", "server.pem", "--tls-key", "server.key"];
+
+        let args = [&["--tcp", "127.0.0.1:50051"][..], &tls].concat();
+        assert!(matches!(
+            config("tls-missing", None, &args),
+            Err(ConfigError::MissingTls)
+        ));
+
+        // The TLS options can come from different sources.
+        let toml = "[tls]\nclient_ca = \"ca.pem\"";
+        let config = config("tls", Some(toml), &args).expect("valid config");
+        assert_eq!(
+            config.tcp,
+            Some(TcpConfig {
+                addr: "127.0.0.1:50051".parse().unwrap(),
+                cert: "server.pem".into(),
+                key: "server.key".into(),
+                client_ca: "ca.pem".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn limits_must_not_be_zero() {
+        assert!(matches!(
+            config("zero-workers", None, &["--workers", "0"]),
+            Err(ConfigError::Zero("workers"))
+        ));
+        assert!(matches!(
+            config("zero-concurrency", Some("concurrency_limit = 0"), &[]),
+            Err(ConfigError::Zero("concurrency_limit"))
+        ));
+        assert!(matches!(
+            config("zero-calls", None, &["--max-calls", "0"]),
+            Err(ConfigError::Zero("max_calls"))
+        ));
+    }
+}
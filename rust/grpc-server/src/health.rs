@@ -0,0 +1,114 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+mod proto {
+    tonic::include_proto!("grpc.health.v1");
+}
+
+pub use proto::health_server::HealthServer;
+
+use {
+    futures::Stream,
+    proto::{
+        health_check_response::ServingStatus, health_server::Health,
+        HealthCheckRequest, HealthCheckResponse,
+    },
+    std::pin::Pin,
+    tokio::sync::watch,
+    tonic::{Request, Response, Status},
+};
+
+/// Services whose health is reported, besides the server as a whole under
+/// the empty name. They all share the status of the server.
+const SERVICES: &[&str] = &["", "signer.Signer"];
+
+/// Sets the serving status reported by the [`HealthService`].
+pub struct HealthReporter(watch::Sender<ServingStatus>);
+
+impl HealthReporter {
+    /// Report the services as no longer serving, and end the watches so they
+    /// don't hold up the shutdown of the server.
+    pub fn shut_down(self) {
+        let _ = self.0.send(ServingStatus::NotServing);
+    }
+}
+
+/// Implementation of the standard `grpc.health.v1.Health` service.
+#[derive(Clone)]
+pub struct HealthService {
+    status: watch::Receiver<ServingStatus>,
+}
+
+/// Create a health service reporting the services as serving, until the
+/// reporter shuts them down.
+pub fn health_service() -> (HealthReporter, HealthServer<HealthService>) {
+    let (tx, rx) = watch::channel(ServingStatus::Serving);
+    let service = HealthServer::new(HealthService { status: rx });
+
+    (HealthReporter(tx), service)
+}
+
+fn response(status: ServingStatus) -> HealthCheckResponse {
+    HealthCheckResponse {
+        status: status as i32,
+    }
+}
+
+#[tonic::async_trait]
+impl Health for HealthService {
+    /// Report the current status of a service
+    async fn check(
+        &self,
+        request: Request<HealthCheckRequest>,
+    ) -> Result<Response<HealthCheckResponse>, Status> {
+        if !SERVICES.contains(&request.get_ref().service.as_str()) {
+            return Err(Status::not_found("unknown service"));
+        }
+
+        let status = *self.status.borrow();
+        Ok(Response::new(response(status)))
+    }
+
+    type WatchStream = Pin<
+        Box<
+            dyn Stream<Item = Result<HealthCheckResponse, Status>>
+                + Send
+                + Sync
+                + 'static,
+        >,
+    >;
+
+    /// Report the status of a service, and then every change of it until the
+    /// server shuts down
+    async fn watch(
+        &self,
+        request: Request<HealthCheckRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        let known = SERVICES.contains(&request.get_ref().service.as_str());
+        let mut status = self.status.clone();
+
+        let responses = async_stream::try_stream! {
+            if !known {
+                // the set of services is fixed, so the status of an unknown
+                // one never changes
+                yield response(ServingStatus::ServiceUnknown);
+                while status.changed().await.is_ok() {}
+                return;
+            }
+
+            loop {
+                let current = *status.borrow();
+                yield response(current);
+
+                if status.changed().await.is_err() {
+                    break;
+                }
+            }
+        };
+
+        Ok(Response::new(Box::pin(responses)))
+    }
+}
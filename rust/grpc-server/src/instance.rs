@@ -0,0 +1,170 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use {
+    std::fs::{self, File, OpenOptions},
+    std::io::{Error, ErrorKind, Read, Result, Write},
+    std::os::unix::fs::FileTypeExt,
+    std::os::unix::io::AsRawFd,
+    std::os::unix::net::UnixStream,
+    std::path::{Path, PathBuf},
+    std::process,
+};
+
+/// A PID file holding an exclusive lock, so that only one instance of the
+/// service runs with it at a time. The lock is released when the process
+/// exits, even if it crashes, and the file is removed on drop.
+pub struct PidFile {
+    path: PathBuf,
+    _file: File,
+}
+
+impl PidFile {
+    /// Lock the PID file at `path` and write the PID of the process to it,
+    /// failing if another instance holds the lock.
+    pub fn acquire<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+
+        // SAFETY: the file descriptor is valid for as long as the file is
+        // open, and `flock` doesn't access any memory
+        let res = unsafe {
+            libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB)
+        };
+        if res != 0 {
+            let err = Error::last_os_error();
+            if err.kind() != ErrorKind::WouldBlock {
+                return Err(err);
+            }
+
+            let mut pid = String::new();
+            file.read_to_string(&mut pid)?;
+            return Err(Error::other(format!(
+                "another instance is running with pid {}, locking {}",
+                pid.trim(),
+                path.display()
+            )));
+        }
+
+        file.set_len(0)?;
+        writeln!(file, "{}", process::id())?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            _file: file,
+        })
+    }
+}
+
+impl Drop for PidFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Remove the socket at `path` if it was left behind by an instance that
+/// didn't shut down cleanly. Fails if something is still listening on it, or
+/// if the path exists and isn't a socket.
+pub fn remove_stale_socket<P: AsRef<Path>>(path: P) -> Result<()> {
+    let path = path.as_ref();
+    let metadata = match fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    if !metadata.file_type().is_socket() {
+        return Err(Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} exists and is not a socket", path.display()),
+        ));
+    }
+
+    match UnixStream::connect(path) {
+        Ok(_) => Err(Error::new(
+            ErrorKind::AddrInUse,
+            format!("another instance is listening on {}", path.display()),
+        )),
+        Err(e) if e.kind() == ErrorKind::ConnectionRefused => {
+            println!("removing stale socket at path: {}", path.display());
+            fs::remove_file(path)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::net::UnixListener;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "bls12381svc-{}-{}",
+            process::id(),
+            name
+        ))
+    }
+
+    #[test]
+    fn pid_file_locks_out_other_instances() -> Result<()> {
+        let path = temp_path("instance.pid");
+        let _ = fs::remove_file(&path);
+
+        let pid_file = PidFile::acquire(&path)?;
+        let pid = process::id().to_string();
+        assert_eq!(fs::read_to_string(&path)?.trim(), pid);
+
+        let err = PidFile::acquire(&path).err().expect("the file is locked");
+        assert!(err.to_string().contains(&format!("pid {}", pid)));
+
+        // the lock is released along with the file
+        drop(pid_file);
+        assert!(!path.exists());
+        let _pid_file = PidFile::acquire(&path)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn stale_sockets_are_removed() -> Result<()> {
+        let path = temp_path("stale.sock");
+        let _ = fs::remove_file(&path);
+
+        remove_stale_socket(&path)?;
+
+        // the socket of a listener that is gone is removed
+        drop(UnixListener::bind(&path)?);
+        assert!(path.exists());
+        remove_stale_socket(&path)?;
+        assert!(!path.exists());
+
+        // the socket of a live listener is kept
+        let _listener = UnixListener::bind(&path)?;
+        let err = remove_stale_socket(&path).expect_err("socket in use");
+        assert_eq!(err.kind(), ErrorKind::AddrInUse);
+        assert!(path.exists());
+
+        fs::remove_file(&path)
+    }
+
+    #[test]
+    fn other_files_are_kept() -> Result<()> {
+        let path = temp_path("file.sock");
+        fs::write(&path, "")?;
+
+        let err = remove_stale_socket(&path).expect_err("not a socket");
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+        assert!(path.exists());
+
+        fs::remove_file(&path)
+    }
+}
@@ -0,0 +1,116 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use {
+    dusk_bls12_381_sign::{PublicKey, SecretKey},
+    dusk_bytes::Serializable,
+    std::collections::BTreeMap,
+    std::convert::TryInto,
+    std::ffi::OsStr,
+    std::fs,
+    std::io::{Error, ErrorKind, Result},
+    std::path::Path,
+};
+
+/// Extension of the key files in a keystore directory.
+pub const KEY_EXTENSION: &str = "key";
+
+/// Secret keys held by the service, identified by the bytes of their public
+/// key, so that clients can sign without ever sending a secret key.
+#[derive(Default)]
+pub struct Keystore {
+    keys: BTreeMap<[u8; PublicKey::SIZE], (SecretKey, PublicKey)>,
+}
+
+impl Keystore {
+    /// Load every `.key` file in `dir`, each holding a hex encoded secret
+    /// key. Other files are ignored.
+    ///
+    /// Key files readable or writable by their group or others are refused,
+    /// since their keys may have leaked.
+    pub fn load<P: AsRef<Path>>(dir: P) -> Result<Self> {
+        let mut keystore = Self::default();
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension() != Some(OsStr::new(KEY_EXTENSION)) {
+                continue;
+            }
+
+            let sk = check_private(&path).and_then(|_| read_key(&path));
+            let sk = sk.map_err(|e| {
+                Error::new(e.kind(), format!("{}: {}", path.display(), e))
+            })?;
+            keystore.insert(sk);
+        }
+
+        Ok(keystore)
+    }
+
+    /// Add a secret key to the keystore, returning its public key.
+    pub fn insert(&mut self, sk: SecretKey) -> PublicKey {
+        let pk = PublicKey::from(&sk);
+        self.keys.insert(pk.to_bytes(), (sk, pk));
+        pk
+    }
+
+    /// Get the key pair identified by the bytes of its public key.
+    pub fn get(&self, id: &[u8]) -> Option<&(SecretKey, PublicKey)> {
+        let id: [u8; PublicKey::SIZE] = id.try_into().ok()?;
+        self.keys.get(&id)
+    }
+
+    /// The public keys of the keystore, ordered by their bytes.
+    pub fn public_keys(&self) -> impl Iterator<Item = &PublicKey> {
+        self.keys.values().map(|(_, pk)| pk)
+    }
+
+    /// Number of keys in the keystore.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Whether the keystore holds no key.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+/// Read a hex encoded secret key from a file.
+pub fn read_key<P: AsRef<Path>>(path: P) -> Result<SecretKey> {
+    let hex = fs::read_to_string(path)?;
+    let bytes = hex::decode(hex.trim())
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+
+    let bytes: [u8; SecretKey::SIZE] = bytes.try_into().map_err(|_| {
+        Error::new(ErrorKind::InvalidData, "wrong secret key length")
+    })?;
+
+    SecretKey::from_bytes(&bytes)
+        .map_err(|_| Error::new(ErrorKind::InvalidData, "invalid secret key"))
+}
+
+/// Check that a file is only accessible by its owner.
+#[cfg(unix)]
+fn check_private(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = fs::metadata(path)?.permissions().mode();
+    if mode & 0o077 != 0 {
+        let msg = format!(
+            "permissions {:o} give access to group or others, expected 600",
+            mode & 0o777
+        );
+        return Err(Error::new(ErrorKind::PermissionDenied, msg));
+    }
+
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn check_private(_path: &Path) -> Result<()> {
+    Ok(())
+}
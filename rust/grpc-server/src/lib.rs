@@ -0,0 +1,13 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Protocol of the signer service, and the parts of the service and its
+//! command line tools shared between them.
+
+pub mod cli;
+pub mod keystore;
+pub mod proto;
+pub mod remote;
@@ -0,0 +1,220 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use {
+    futures::{Future, StreamExt},
+    hyper::{Body, Request},
+    std::error::Error as StdError,
+    std::pin::Pin,
+    std::sync::Arc,
+    std::task::{Context, Poll},
+    thiserror::Error,
+    tokio::sync::Semaphore,
+    tonic::Status,
+    tower::{util::MapRequestLayer, Layer, Service},
+};
+
+type BoxError = Box<dyn StdError + Send + Sync>;
+
+/// Request exceeding a limit of the service.
+#[derive(Debug, Error)]
+pub enum LimitError {
+    #[error("message of {0} bytes exceeds the limit of {1} bytes")]
+    MessageSize(usize, usize),
+    #[error("{0} items exceed the limit of {1} items")]
+    ListLength(usize, usize),
+}
+
+impl From<LimitError> for Status {
+    fn from(err: LimitError) -> Self {
+        Status::resource_exhausted(err.to_string())
+    }
+}
+
+/// Length of the prefix of every gRPC message: a compression flag followed by
+/// the length of the message, in big endian.
+const PREFIX_LEN: usize = 5;
+
+/// Check of the length prefixes of the messages of a request body, as the
+/// body streams in.
+struct MessageLimit {
+    max: usize,
+    prefix: [u8; PREFIX_LEN],
+    /// Bytes of the prefix of the next message read so far
+    filled: usize,
+    /// Bytes of the current message left to read
+    remaining: usize,
+}
+
+impl MessageLimit {
+    fn new(max: usize) -> Self {
+        Self {
+            max,
+            prefix: [0; PREFIX_LEN],
+            filled: 0,
+            remaining: 0,
+        }
+    }
+
+    fn check(&mut self, mut chunk: &[u8]) -> Result<(), LimitError> {
+        while !chunk.is_empty() {
+            if self.remaining > 0 {
+                let n = self.remaining.min(chunk.len());
+                self.remaining -= n;
+                chunk = &chunk[n..];
+                continue;
+            }
+
+            let n = (PREFIX_LEN - self.filled).min(chunk.len());
+            self.prefix[self.filled..self.filled + n]
+                .copy_from_slice(&chunk[..n]);
+            self.filled += n;
+            chunk = &chunk[n..];
+
+            if self.filled == PREFIX_LEN {
+                let [_, len @ ..] = self.prefix;
+                let len = u32::from_be_bytes(len) as usize;
+                if len > self.max {
+                    return Err(LimitError::MessageSize(len, self.max));
+                }
+                self.filled = 0;
+                self.remaining = len;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Layer limiting the messages of the requests to `max` bytes. The calls
+/// sending a larger message fail with `RESOURCE_EXHAUSTED` as soon as its
+/// length is read, before the message is buffered.
+pub fn message_limit_layer(
+    max: usize,
+) -> MapRequestLayer<impl FnMut(Request<Body>) -> Request<Body> + Clone> {
+    MapRequestLayer::new(move |request: Request<Body>| {
+        let (parts, body) = request.into_parts();
+        let mut limit = MessageLimit::new(max);
+        let body = body.map(move |chunk| {
+            let chunk = chunk?;
+            // the status is found by tonic in the source of the body error
+            limit.check(&chunk).map_err(Status::from)?;
+            Ok::<_, Box<dyn StdError + Send + Sync>>(chunk)
+        });
+
+        Request::from_parts(parts, Body::wrap_stream(body))
+    })
+}
+
+/// Tower layer limiting the calls in flight across all the connections of the
+/// layered services, the others waiting for their turn.
+///
+/// Unlike the concurrency limits of tower, which take their permit as soon
+/// as the service is polled for readiness, a permit is only taken once a call
+/// is made, as the server polls every connection for readiness, busy or not.
+/// Calls hold their permit until they respond, which for the streaming calls
+/// is as soon as the stream starts.
+#[derive(Debug, Clone)]
+pub struct CallLimitLayer(Arc<Semaphore>);
+
+impl CallLimitLayer {
+    pub fn new(max: usize) -> Self {
+        Self(Arc::new(Semaphore::new(max)))
+    }
+}
+
+impl<S> Layer<S> for CallLimitLayer {
+    type Service = CallLimit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CallLimit {
+            inner,
+            permits: self.0.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CallLimit<S> {
+    inner: S,
+    permits: Arc<Semaphore>,
+}
+
+impl<S, R> Service<R> for CallLimit<S>
+where
+    S: Service<R>,
+    S::Error: Into<BoxError>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = BoxError;
+    type Future = Pin<
+        Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, request: R) -> Self::Future {
+        let permits = self.permits.clone();
+        // the futures of the services do nothing until polled
+        let response = self.inner.call(request);
+
+        Box::pin(async move {
+            let _permit = permits
+                .acquire_owned()
+                .await
+                .map_err(|_| Status::unavailable("call limit closed"))?;
+            response.await.map_err(Into::into)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::sync::oneshot;
+    use tokio::time::timeout;
+    use tower::{service_fn, ServiceExt};
+
+    #[tokio::test]
+    async fn calls_are_limited_across_services() {
+        let layer = CallLimitLayer::new(1);
+        // a service per connection, each waiting for its call to be released
+        let connection = || {
+            layer.layer(service_fn(
+                |release: oneshot::Receiver<()>| async move {
+                    release.await.map_err(BoxError::from)
+                },
+            ))
+        };
+        let mut first = connection();
+        let mut second = connection();
+
+        // idle services don't hold a permit
+        let (release_first, rx) = oneshot::channel();
+        let first = first.ready().await.expect("ready").call(rx);
+        let (release_second, rx) = oneshot::channel();
+        let second = second.ready().await.expect("ready").call(rx);
+
+        // the second call waits for the first, even once released
+        let wait = Duration::from_millis(100);
+        let mut first = tokio::spawn(first);
+        assert!(timeout(wait, &mut first).await.is_err());
+        release_second.send(()).expect("call is waiting");
+        let mut second = tokio::spawn(second);
+        assert!(timeout(wait, &mut second).await.is_err());
+
+        release_first.send(()).expect("call is waiting");
+        assert!(matches!(timeout(wait, first).await, Ok(Ok(Ok(())))));
+        assert!(matches!(timeout(wait, second).await, Ok(Ok(Ok(())))));
+    }
+}
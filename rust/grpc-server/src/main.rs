@@ -6,66 +6,255 @@
 
 #![cfg_attr(not(unix), allow(unused_imports))]
 
+mod acl;
+mod config;
+mod health;
+#[cfg(unix)]
+mod instance;
+mod limits;
+mod metrics;
+mod pool;
+mod reflection;
 #[cfg(unix)]
 mod unix;
 
-tonic::include_proto!("signer");
-
 use {
-    aggregate_response::Agg::Code,
-    create_apk_response::Apk::Apk,
-    dusk_bls12_381_sign::{PublicKey, SecretKey, Signature, APK},
+    acl::{audit_denied, tag_rpc, Acl, AclInterceptor, Client, Grant},
+    config::{Config, TcpConfig, MAX_LIST_LENGTH},
+    dusk_bls12_381_sign::{verify_batch, PublicKey, SecretKey, Signature, APK},
+    dusk_bls12_381_sign_ipc::{
+        keystore::Keystore,
+        proto::{
+            aggregate_response::Agg,
+            create_apk_response::Apk,
+            sign_response::Sig,
+            signer_server::{Signer, SignerServer},
+            verify_response::Ver,
+            *,
+        },
+    },
     dusk_bytes::Serializable,
-    futures::TryFutureExt,
-    sign_response::Sig::Signature as ResponseSignature,
-    signer_server::{Signer, SignerServer},
-    std::fs::remove_file,
-    std::process::exit,
-    tokio::net::UnixListener,
-    tonic::{transport::Server, Request, Response, Status},
-    verify_response::Ver,
+    futures::{future, Future, Stream, TryFutureExt},
+    health::{health_service, HealthReporter},
+    instance::{remove_stale_socket, PidFile},
+    limits::{message_limit_layer, CallLimitLayer, LimitError},
+    metrics::{respond, Metrics, MetricsLayer, Observe, Outcome},
+    pool::WorkPool,
+    reflection::reflection_service,
+    std::convert::TryInto,
+    std::fs::{read, remove_file, set_permissions, Permissions},
+    std::os::unix::fs::PermissionsExt,
+    std::pin::Pin,
+    std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard},
+    std::time::Duration,
+    tokio::signal::unix::{signal, SignalKind},
+    tokio::sync::watch,
+    tonic::{
+        transport::{Certificate, Identity, Server, ServerTlsConfig},
+        Request, Response, Status, Streaming,
+    },
+    tower::{util::MapRequestLayer, ServiceBuilder},
 };
 
-#[derive(Default)]
-pub struct MySign {}
+#[derive(Clone)]
+pub struct MySign {
+    keystore: Arc<RwLock<Keystore>>,
+    acl: Arc<RwLock<Acl>>,
+    pool: WorkPool,
+    max_list_length: usize,
+}
 
-/// The following macros are written to minimize memory handling with
-/// [core::mem::transmute], and by early return on error from the calling
-/// function to reduce repeated match branches that are basically all the same
-#[macro_export]
-macro_rules! slice_as_array_transmute {
-    ($slice:expr) => {
-        ::core::mem::transmute($slice)
-    };
+impl Default for MySign {
+    fn default() -> Self {
+        Self {
+            keystore: Arc::default(),
+            acl: Arc::default(),
+            pool: WorkPool::default(),
+            max_list_length: MAX_LIST_LENGTH,
+        }
+    }
 }
 
-#[macro_export]
-macro_rules! slice_as {
-    ($slice:expr, $wrapper:ty, $note:literal) => {{
-        unsafe fn this_transmute(xs: &[u8]) -> &[u8; <$wrapper>::SIZE] {
-            slice_as_array_transmute!(xs.as_ptr())
-        }
-
-        let s: &[u8] = $slice;
-        if s.len() != <$wrapper>::SIZE {
-            return Err(Status::invalid_argument(format!(
-                "{}: provided vector is wrong length: {} should be {}",
-                $note,
-                s.len(),
-                <$wrapper>::SIZE,
-            )));
-        } else {
-            match <$wrapper>::from_bytes(unsafe { this_transmute(s) }) {
-                Ok(v) => v,
-                Err(_) => {
-                    return Err(Status::invalid_argument(format!(
-                        "unable to convert to type {}",
-                        $note
-                    )))
-                }
-            }
+impl MySign {
+    /// Access the keystore, which is replaced when the service is reloaded
+    fn keystore(&self) -> RwLockReadGuard<'_, Keystore> {
+        self.keystore.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Reject the lists of a request longer than the configured limit
+    fn check_list_length(&self, len: usize) -> Result<(), LimitError> {
+        if len > self.max_list_length {
+            return Err(LimitError::ListLength(len, self.max_list_length));
+        }
+        Ok(())
+    }
+}
+
+/// Deserialize a value from a slice, failing with [`Error::BlsInvalidBytes`]
+/// if the slice has the wrong length or doesn't hold a valid value
+fn from_slice<T: Serializable<N>, const N: usize>(
+    bytes: &[u8],
+) -> Result<T, Error> {
+    let bytes: &[u8; N] =
+        bytes.try_into().map_err(|_| Error::BlsInvalidBytes)?;
+    T::from_bytes(bytes).map_err(|_| Error::BlsInvalidBytes)
+}
+
+impl Observe for GenerateKeysResponse {}
+
+impl Observe for ListKeysResponse {}
+
+impl Observe for SignResponse {
+    fn outcome(&self) -> Outcome {
+        let errors = match &self.sig {
+            Some(Sig::Error(err)) => vec![*err],
+            _ => vec![],
+        };
+        Outcome {
+            errors,
+            batch_size: None,
+        }
+    }
+}
+
+impl Observe for VerifyResponse {
+    fn outcome(&self) -> Outcome {
+        let errors = match &self.ver {
+            Some(Ver::Error(err)) => vec![*err],
+            _ => vec![],
+        };
+        Outcome {
+            errors,
+            batch_size: None,
+        }
+    }
+}
+
+impl Observe for CreateApkResponse {
+    fn outcome(&self) -> Outcome {
+        let errors = match &self.apk {
+            Some(Apk::Error(err)) => vec![*err],
+            _ => vec![],
+        };
+        Outcome {
+            errors,
+            batch_size: None,
+        }
+    }
+}
+
+impl Observe for AggregateResponse {
+    fn outcome(&self) -> Outcome {
+        let errors = match &self.agg {
+            Some(Agg::Error(err)) => vec![*err],
+            _ => vec![],
+        };
+        Outcome {
+            errors,
+            batch_size: None,
         }
-    }};
+    }
+}
+
+impl Observe for BatchVerifyResponse {
+    fn outcome(&self) -> Outcome {
+        Outcome {
+            errors: self
+                .results
+                .iter()
+                .flat_map(|r| r.outcome().errors)
+                .collect(),
+            batch_size: Some(self.results.len()),
+        }
+    }
+}
+
+impl Observe for BatchSignResponse {
+    fn outcome(&self) -> Outcome {
+        Outcome {
+            errors: self
+                .results
+                .iter()
+                .flat_map(|r| r.outcome().errors)
+                .collect(),
+            batch_size: Some(self.results.len()),
+        }
+    }
+}
+
+/// Decode the aggregated public key and the signature of a verification
+fn decode_verify(req: &VerifyRequest) -> Result<(APK, Signature), Error> {
+    Ok((from_slice(&req.apk)?, from_slice(&req.signature)?))
+}
+
+/// Verify a single signature
+fn verify_item(req: &VerifyRequest) -> Result<(), Error> {
+    let (apk, sig) = decode_verify(req)?;
+    Ok(apk.verify(&sig, &req.message)?)
+}
+
+/// Sign a single message
+fn sign_item(req: &SignRequest) -> Result<Signature, Error> {
+    let sk: SecretKey = from_slice(&req.secret_key)?;
+    let pk: PublicKey = from_slice(&req.public_key)?;
+    Ok(sk.sign(&pk, &req.message))
+}
+
+/// Aggregate a collection of public keys into an aggregated public key
+fn aggregate_pk_item(req: &AggregatePkRequest) -> Result<APK, Error> {
+    let mut apk: APK = from_slice(&req.apk)?;
+    let pks = req
+        .keys
+        .iter()
+        .map(|pk| from_slice(pk))
+        .collect::<Result<Vec<PublicKey>, _>>()?;
+    apk.aggregate(&pks);
+    Ok(apk)
+}
+
+/// Aggregate a collection of signatures into an aggregated signature
+fn aggregate_sig_item(req: &AggregateSigRequest) -> Result<Signature, Error> {
+    let sig: Signature = from_slice(&req.signature)?;
+    let sigs = req
+        .signatures
+        .iter()
+        .map(|sig| from_slice(sig))
+        .collect::<Result<Vec<Signature>, _>>()?;
+    Ok(sig.aggregate(&sigs))
+}
+
+/// Verify a batch of signatures at once, falling back to verifying them one
+/// by one if the batch is invalid
+fn verify_items(items: &[VerifyRequest]) -> Vec<VerifyResponse> {
+    let decoded: Vec<_> = items.iter().map(decode_verify).collect();
+
+    // verify all the well formed items at once
+    let batch: Vec<(APK, Signature, &[u8])> = decoded
+        .iter()
+        .zip(items)
+        .filter_map(|(keys, item)| {
+            let (apk, sig) = keys.as_ref().ok()?;
+            Some((*apk, *sig, &item.message[..]))
+        })
+        .collect();
+    let all_valid = verify_batch(&mut rand_core::OsRng, &batch).is_ok();
+
+    // if the batch is invalid, find out which items are by verifying them one
+    // by one
+    decoded
+        .iter()
+        .zip(items)
+        .map(|(keys, item)| {
+            let res = match keys {
+                Ok(_) if all_valid => Ok(()),
+                Ok((apk, sig)) => {
+                    apk.verify(sig, &item.message).map_err(Error::from)
+                }
+                Err(err) => Err(*err),
+            };
+            res.into()
+        })
+        .collect()
 }
 
 #[tonic::async_trait]
@@ -81,7 +270,7 @@ impl Signer for MySign {
         let sk = SecretKey::random(&mut rand_core::OsRng);
 
         // construct the gRPC response from the key and return it
-        Ok(Response::new(GenerateKeysResponse {
+        Ok(respond(GenerateKeysResponse {
             secret_key: sk.to_bytes().to_vec(),
             public_key: PublicKey::from(&sk).to_bytes().to_vec(),
         }))
@@ -91,19 +280,61 @@ impl Signer for MySign {
     async fn sign(
         &self,
         request: Request<SignRequest>,
+    ) -> Result<Response<SignResponse>, Status> {
+        let req = request.into_inner();
+        let res = self.pool.run(move || sign_item(&req)).await?;
+
+        Ok(respond(res.into()))
+    }
+
+    /// Sign a message with a key pair held by the service, identified by its
+    /// public key
+    async fn sign_with_key_id(
+        &self,
+        request: Request<SignWithKeyIdRequest>,
     ) -> Result<Response<SignResponse>, Status> {
         // access the request parameters
         let req = request.get_ref();
-        let sk = slice_as!(&req.secret_key, SecretKey, "SecretKey");
-        let pk = slice_as!(&req.public_key, PublicKey, "PublicKey");
-        // sign the message
-        let res =
-            ResponseSignature(sk.sign(&pk, &req.message).to_bytes().to_vec());
-
-        // return the signature wrapped in the response type
-        Ok(Response::new(SignResponse {
-            sig: Option::Some(res),
-        }))
+
+        // the access control list may restrict the keys of the client
+        if let Some(grant) = request.extensions().get::<Grant>() {
+            if !grant.allows_key(&req.key_id) {
+                audit_denied(
+                    "SignWithKeyId",
+                    Client::from(&request),
+                    "key not allowed",
+                );
+                return Err(Status::permission_denied("key not allowed"));
+            }
+        }
+
+        let key = self.keystore().get(&req.key_id).copied();
+        let res = match key {
+            Some((sk, pk)) => {
+                let message = request.into_inner().message;
+                Ok(self.pool.run(move || sk.sign(&pk, &message)).await?)
+            }
+            None => Err(Error::UnknownKeyId),
+        };
+
+        Ok(respond(res.into()))
+    }
+
+    /// List the public keys of the key pairs held by the service, that the
+    /// client may sign with
+    async fn list_keys(
+        &self,
+        request: Request<ListKeysRequest>,
+    ) -> Result<Response<ListKeysResponse>, Status> {
+        let grant = request.extensions().get::<Grant>();
+        let public_keys = self
+            .keystore()
+            .public_keys()
+            .map(|pk| pk.to_bytes().to_vec())
+            .filter(|pk| grant.iter().all(|grant| grant.allows_key(pk)))
+            .collect();
+
+        Ok(respond(ListKeysResponse { public_keys }))
     }
 
     /// Verify a BLS12-381 signature on a message with a given public key
@@ -111,19 +342,99 @@ impl Signer for MySign {
         &self,
         request: Request<VerifyRequest>,
     ) -> Result<Response<VerifyResponse>, Status> {
-        // access the request parameters
-        let req = request.get_ref();
-        let apk = slice_as!(&req.apk, APK, "APK");
-        let sig = slice_as!(&req.signature, Signature, "Signature");
+        let req = request.into_inner();
+        let res = self.pool.run(move || verify_item(&req)).await?;
 
-        // verify the message matches the signature and the signature matches
-        // the given public key
-        let res = apk.verify(&sig, &req.message);
+        Ok(respond(res.into()))
+    }
 
-        // return whether the verification returned no error
-        Ok(Response::new(VerifyResponse {
-            ver: Some(Ver::Valid(!res.is_err())),
-        }))
+    /// Verify a batch of BLS12-381 signatures, each on its own message and
+    /// with its own aggregated public key
+    async fn batch_verify(
+        &self,
+        request: Request<BatchVerifyRequest>,
+    ) -> Result<Response<BatchVerifyResponse>, Status> {
+        let items = request.into_inner().items;
+        self.check_list_length(items.len())?;
+        let results = self.pool.run(move || verify_items(&items)).await?;
+
+        Ok(respond(BatchVerifyResponse { results }))
+    }
+
+    /// Sign a batch of messages, each with a key pair held by the service,
+    /// identified by its public key
+    async fn batch_sign(
+        &self,
+        request: Request<BatchSignRequest>,
+    ) -> Result<Response<BatchSignResponse>, Status> {
+        self.check_list_length(request.get_ref().items.len())?;
+
+        // the access control list may restrict the keys of the client, and
+        // the batch is denied if any of its keys isn't allowed
+        if let Some(grant) = request.extensions().get::<Grant>() {
+            let items = &request.get_ref().items;
+            if !items.iter().all(|item| grant.allows_key(&item.key_id)) {
+                audit_denied(
+                    "BatchSign",
+                    Client::from(&request),
+                    "key not allowed",
+                );
+                return Err(Status::permission_denied("key not allowed"));
+            }
+        }
+
+        let items = request.into_inner().items;
+        let keys: Vec<_> = {
+            let keystore = self.keystore();
+            items
+                .iter()
+                .map(|item| keystore.get(&item.key_id).copied())
+                .collect()
+        };
+        let results = self
+            .pool
+            .run(move || {
+                items
+                    .iter()
+                    .zip(keys)
+                    .map(|(item, key)| match key {
+                        Some((sk, pk)) => Ok(sk.sign(&pk, &item.message)),
+                        None => Err(Error::UnknownKeyId),
+                    })
+                    .map(SignResponse::from)
+                    .collect()
+            })
+            .await?;
+
+        Ok(respond(BatchSignResponse { results }))
+    }
+
+    type VerifyStreamStream = Pin<
+        Box<
+            dyn Stream<Item = Result<VerifyResponse, Status>>
+                + Send
+                + Sync
+                + 'static,
+        >,
+    >;
+
+    /// Verify a stream of BLS12-381 signatures, responding to each request
+    /// in order
+    async fn verify_stream(
+        &self,
+        request: Request<Streaming<VerifyRequest>>,
+    ) -> Result<Response<Self::VerifyStreamStream>, Status> {
+        let mut requests = request.into_inner();
+        let pool = self.pool.clone();
+
+        let responses = async_stream::try_stream! {
+            while let Some(req) = requests.message().await? {
+                let res = pool.run(move || verify_item(&req)).await?;
+                yield res.into();
+            }
+        };
+
+        Ok(Response::new(Box::pin(responses)))
     }
 
     /// Create an aggregated public key from a public key
@@ -131,13 +442,15 @@ impl Signer for MySign {
         &self,
         request: Request<CreateApkRequest>,
     ) -> Result<Response<CreateApkResponse>, Status> {
-        // access the request parameters
-        let req = request.get_ref();
-        let apk = slice_as!(&req.public_key, PublicKey, "PublicKey");
-        let apk = APK::from(&apk);
-        Ok(Response::new(CreateApkResponse {
-            apk: Some(Apk(apk.to_bytes().to_vec())),
-        }))
+        let public_key = request.into_inner().public_key;
+        let res = self
+            .pool
+            .run(move || {
+                from_slice(&public_key).map(|pk: PublicKey| APK::from(&pk))
+            })
+            .await?;
+
+        Ok(respond(res.into()))
     }
 
     /// Aggregate a collection of public keys to an aggregated public key
@@ -145,23 +458,15 @@ impl Signer for MySign {
         &self,
         request: Request<AggregatePkRequest>,
     ) -> Result<Response<AggregateResponse>, Status> {
-        // access the request parameters
-        let req = request.get_ref();
-        // get the apk first
-        let apk = slice_as!(&req.apk, PublicKey, "PublicKey");
-        let mut apk = APK::from_bytes(&apk.to_bytes()).unwrap();
-        // collect the list of public keys into a vector
-        let mut pks = Vec::with_capacity(req.keys.len());
-        for elem in &req.keys {
-            pks.push(slice_as!(&elem, PublicKey, "PublicKey"));
-        }
-        // aggregate the keys
-        apk.aggregate(&pks);
-        let bytes = &apk.to_bytes();
-        // convert public key to aggregated public key and return it
-        Ok(Response::new(AggregateResponse {
-            agg: Some(Code(bytes.to_vec())),
-        }))
+        let req = request.into_inner();
+        self.check_list_length(req.keys.len())?;
+        let res = self
+            .pool
+            .run(move || aggregate_pk_item(&req))
+            .await?
+            .map(|apk| apk.to_bytes().to_vec());
+
+        Ok(respond(res.into()))
     }
 
     /// Aggregate a collection of signatures into an aggregated signature
@@ -169,36 +474,37 @@ impl Signer for MySign {
         &self,
         request: Request<AggregateSigRequest>,
     ) -> Result<Response<AggregateResponse>, Status> {
-        // access the request parameters
-        let req = request.get_ref();
-        let sig = slice_as!(&req.signature, Signature, "Signature");
-
-        // convert the raw bytes from the message to a collection of signatures
-        let mut sigs: Vec<Signature> = Vec::with_capacity(req.signatures.len());
-        // collect the list of public keys into a vector
-        for elem in &req.signatures {
-            sigs.push(slice_as!(&elem, Signature, "Signature"));
-        }
+        let req = request.into_inner();
+        self.check_list_length(req.signatures.len())?;
+        let res = self
+            .pool
+            .run(move || aggregate_sig_item(&req))
+            .await?
+            .map(|sig| sig.to_bytes().to_vec());
 
-        // aggregate the signatures
-        let sig = sig.aggregate(&sigs);
-
-        let bytes = Code(sig.to_bytes().to_vec());
-
-        // convert aggregate signature to bytes and return
-        Ok(Response::new(AggregateResponse { agg: Some(bytes) }))
+        Ok(respond(res.into()))
     }
 }
 
-extern crate ctrlc;
-
-/// Default UDS path that Rusk GRPC-server will connect to.
-pub const SOCKET_PATH: &str = "/tmp/bls12381svc.sock";
-
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Set up a unix domain socket at the default path
-    let uds = UnixListener::bind(SOCKET_PATH)?;
+    let config = Config::load()?;
+
+    // Lock out other instances before touching the socket
+    let _pid_file = PidFile::acquire(&config.pid_file)?;
+
+    let keystore = load_keystore(&config)?;
+    let service = MySign {
+        keystore: Arc::new(RwLock::new(keystore)),
+        acl: Arc::new(RwLock::new(config.acl.clone())),
+        pool: WorkPool::new(config.workers),
+        max_list_length: config.max_list_length,
+    };
+
+    // Set up a unix domain socket at the configured path, replacing the one
+    // of an instance that crashed
+    remove_stale_socket(&config.socket)?;
+    let uds = unix::bind(&config.socket, config.socket_mode)?;
     let incoming = {
         async_stream::stream! {
             loop{
@@ -208,28 +514,707 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     };
 
-    // Make sure to remove the socket, and shut down on interrupt. This is the
-    // normal way to terminate the service
-    ctrlc::set_handler(move || {
-        match remove_file(SOCKET_PATH) {
-            Ok(_) => {
-                println!("\nremoved socket at path: {}", SOCKET_PATH)
-            }
-            Err(e) => {
-                println!("error: {:?}", e)
+    // Shut down on interrupt or termination, draining the in-flight requests,
+    // and reload on hangup. Interrupting is the normal way to terminate the
+    // service
+    let (health, health_service) = health_service();
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    spawn_signal_handler(service.clone(), config.clone(), health, shutdown_tx)?;
+
+    let reflection_service = reflection_service()?;
+    let metrics = Arc::new(Metrics::default());
+    // shared by the listeners, so that the limit holds across all of them
+    let call_limit = CallLimitLayer::new(config.max_calls);
+
+    // The clients of the TCP listener are authenticated by TLS, and subject to
+    // the rules of the access control list matching any client
+    let uds_server = Server::builder()
+        .concurrency_limit_per_connection(config.concurrency_limit)
+        .layer(
+            ServiceBuilder::new()
+                .layer(MetricsLayer::new(metrics.clone()))
+                .layer(call_limit.clone())
+                .layer(message_limit_layer(config.max_message_size))
+                .layer(MapRequestLayer::new(tag_rpc))
+                .into_inner(),
+        )
+        .add_service(SignerServer::with_interceptor(
+            service.clone(),
+            AclInterceptor::new(service.acl.clone()),
+        ))
+        .add_service(health_service.clone())
+        .add_service(reflection_service.clone())
+        .serve_with_incoming_shutdown(incoming, shutdown(shutdown_rx.clone()));
+    let mut servers: Vec<Pin<Box<dyn Future<Output = ServeResult>>>> =
+        vec![Box::pin(uds_server.err_into())];
+
+    if let Some(tcp) = &config.tcp {
+        let interceptor = AclInterceptor::new(service.acl.clone());
+        let tcp_server = Server::builder()
+            .tls_config(tls_config(tcp)?)?
+            .concurrency_limit_per_connection(config.concurrency_limit)
+            .layer(
+                ServiceBuilder::new()
+                    .layer(MetricsLayer::new(metrics.clone()))
+                    .layer(call_limit)
+                    .layer(message_limit_layer(config.max_message_size))
+                    .layer(MapRequestLayer::new(tag_rpc))
+                    .into_inner(),
+            )
+            .add_service(SignerServer::with_interceptor(service, interceptor))
+            .add_service(health_service)
+            .add_service(reflection_service)
+            .serve_with_shutdown(tcp.addr, shutdown(shutdown_rx.clone()));
+        println!("listening on TCP address: {}", tcp.addr);
+        servers.push(Box::pin(tcp_server.err_into()));
+    }
+
+    if let Some(addr) = config.metrics {
+        let listener = std::net::TcpListener::bind(addr)?;
+        let metrics_server =
+            metrics::serve(listener, metrics, shutdown(shutdown_rx.clone()));
+        println!("serving metrics on HTTP address: {}", addr);
+        servers.push(Box::pin(metrics_server.err_into()));
+    }
+
+    let servers = future::try_join_all(servers);
+    let res = drain(servers, shutdown_rx, config.shutdown_timeout)
+        .await
+        .unwrap_or_else(|| {
+            println!("error: dropping the requests still in flight");
+            Ok(Vec::new())
+        });
+
+    // Make sure to remove the socket, even if serving failed
+    match remove_file(&config.socket) {
+        Ok(_) => {
+            println!("removed socket at path: {}", config.socket.display())
+        }
+        Err(e) => println!("error: {:?}", e),
+    };
+
+    res?;
+    Ok(())
+}
+
+type ServeResult = Result<(), Box<dyn std::error::Error>>;
+
+/// Load the keys to sign with by reference, if a keystore is configured
+fn load_keystore(config: &Config) -> std::io::Result<Keystore> {
+    match &config.keystore {
+        Some(dir) => {
+            let keystore = Keystore::load(dir)?;
+            println!(
+                "loaded {} keys from keystore at path: {}",
+                keystore.len(),
+                dir.display()
+            );
+            Ok(keystore)
+        }
+        None => Ok(Keystore::default()),
+    }
+}
+
+/// Resolve once the shutdown is signaled, or the signal handler is gone
+async fn shutdown(mut rx: watch::Receiver<bool>) {
+    let _ = rx.changed().await;
+}
+
+/// Wait for the servers to complete, giving up on them once `timeout` has
+/// elapsed since the shutdown was signaled
+async fn drain<F: Future>(
+    servers: F,
+    rx: watch::Receiver<bool>,
+    timeout: Duration,
+) -> Option<F::Output> {
+    let deadline = async {
+        shutdown(rx).await;
+        tokio::time::sleep(timeout).await;
+    };
+
+    tokio::select! {
+        res = servers => Some(res),
+        _ = deadline => None,
+    }
+}
+
+/// Handle the signals of the process in the background: report the services
+/// as not serving and signal the shutdown on SIGINT or SIGTERM, and reload the
+/// configuration on SIGHUP.
+fn spawn_signal_handler(
+    service: MySign,
+    mut config: Config,
+    health: HealthReporter,
+    shutdown: watch::Sender<bool>,
+) -> std::io::Result<()> {
+    let mut interrupt = signal(SignalKind::interrupt())?;
+    let mut terminate = signal(SignalKind::terminate())?;
+    let mut hangup = signal(SignalKind::hangup())?;
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = interrupt.recv() => break,
+                _ = terminate.recv() => break,
+                _ = hangup.recv() => reload(&service, &mut config),
             }
-        };
-        exit(0);
-    })?;
+        }
+
+        println!("\nshutting down, waiting for in-flight requests");
+        health.shut_down();
+        let _ = shutdown.send(true);
+    });
 
-    Server::builder()
-        .add_service(SignerServer::new(MySign::default()))
-        .serve_with_incoming(incoming)
-        .await?;
     Ok(())
 }
 
+/// Reload the configuration, the keystore and the access control list, keeping
+/// the current ones if they fail to load. The listeners and limits can't be
+/// changed without a restart.
+fn reload(service: &MySign, config: &mut Config) {
+    println!("reloading configuration");
+
+    let new = match Config::load() {
+        Ok(new) => new,
+        Err(e) => {
+            println!("error: unable to reload configuration: {}", e);
+            return;
+        }
+    };
+    let keystore = match load_keystore(&new) {
+        Ok(keystore) => keystore,
+        Err(e) => {
+            println!("error: unable to reload keystore: {}", e);
+            return;
+        }
+    };
+
+    if new.socket != config.socket
+        || new.pid_file != config.pid_file
+        || new.tcp != config.tcp
+        || new.metrics != config.metrics
+        || new.max_message_size != config.max_message_size
+        || new.max_list_length != config.max_list_length
+        || new.concurrency_limit != config.concurrency_limit
+        || new.max_calls != config.max_calls
+        || new.workers != config.workers
+        || new.shutdown_timeout != config.shutdown_timeout
+    {
+        println!("changes to the listeners and limits take effect on restart");
+    }
+    if let Some(mode) = new.socket_mode {
+        if let Err(e) =
+            set_permissions(&config.socket, Permissions::from_mode(mode))
+        {
+            println!("error: unable to set socket permissions: {}", e);
+        }
+    }
+
+    *service
+        .keystore
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = keystore;
+    *service.acl.write().unwrap_or_else(PoisonError::into_inner) =
+        new.acl.clone();
+    config.socket_mode = new.socket_mode;
+    config.keystore = new.keystore;
+    config.acl = new.acl;
+}
+
+/// Build the TLS configuration of the TCP listener, authenticating the
+/// clients with the client CA.
+fn tls_config(tcp: &TcpConfig) -> std::io::Result<ServerTlsConfig> {
+    let cert = read(&tcp.cert)?;
+    let key = read(&tcp.key)?;
+    let client_ca = read(&tcp.client_ca)?;
+
+    Ok(ServerTlsConfig::new()
+        .identity(Identity::from_pem(cert, key))
+        .client_ca_root(Certificate::from_pem(client_ca)))
+}
+
 #[cfg(not(unix))]
 fn main() {
     panic!("Unix Domain Sockets can only be used on unix systems!");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use config::MAX_MESSAGE_SIZE;
+    use dusk_bls12_381_sign_ipc::cli::{CliError, Encoding, Output, Value};
+    use dusk_bls12_381_sign_ipc::remote::{self, Command};
+    use signer_client::SignerClient;
+    use std::io::{Read, Write};
+    use std::os::unix::fs::OpenOptionsExt;
+    use std::path::PathBuf;
+    use tokio::net::UnixListener;
+    use tonic::transport::{Channel, ClientTlsConfig};
+
+    fn bytes(output: &Output, name: &str) -> String {
+        match output.get(name) {
+            Some(Value::Bytes(bytes)) => hex::encode(bytes),
+            other => panic!("expected bytes for {}, got {:?}", name, other),
+        }
+    }
+
+    fn failed_with<T>(res: Result<T, Status>, code: tonic::Code) -> bool {
+        matches!(res, Err(s) if s.code() == code)
+    }
+
+    fn denied<T>(res: Result<T, Status>) -> bool {
+        failed_with(res, tonic::Code::PermissionDenied)
+    }
+
+    /// Serve a service on a socket in the temporary directory, recording its
+    /// metrics
+    fn spawn_service(
+        name: &str,
+        service: MySign,
+        metrics: Arc<Metrics>,
+    ) -> PathBuf {
+        let socket = std::env::temp_dir().join(format!(
+            "bls12381svc-{}-{}.sock",
+            name,
+            std::process::id()
+        ));
+        let _ = remove_file(&socket);
+
+        let uds = UnixListener::bind(&socket).expect("socket should bind");
+        let incoming = async_stream::stream! {
+            loop {
+                yield uds.accept().map_ok(|(st, _)| unix::UnixStream(st)).await;
+            }
+        };
+        let interceptor = AclInterceptor::new(service.acl.clone());
+        tokio::spawn(
+            Server::builder()
+                .layer(
+                    ServiceBuilder::new()
+                        .layer(MetricsLayer::new(metrics))
+                        .layer(message_limit_layer(MAX_MESSAGE_SIZE))
+                        .layer(MapRequestLayer::new(tag_rpc))
+                        .into_inner(),
+                )
+                .add_service(SignerServer::with_interceptor(
+                    service,
+                    interceptor,
+                ))
+                .serve_with_incoming(incoming),
+        );
+
+        socket
+    }
+
+    /// Path of a TLS certificate or key of the tests
+    fn tls_fixture(name: &str) -> PathBuf {
+        [env!("CARGO_MANIFEST_DIR"), "tests", "tls", name]
+            .iter()
+            .collect()
+    }
+
+    /// Serve a service on a TCP port of the loopback interface, over TLS
+    /// authenticating the clients with the test CA
+    async fn spawn_tls_service(service: MySign) -> std::net::SocketAddr {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("listener should bind");
+        let addr = listener.local_addr().expect("listener has an address");
+        let incoming = async_stream::stream! {
+            loop {
+                yield listener.accept().map_ok(|(st, _)| st).await;
+            }
+        };
+
+        let tcp = TcpConfig {
+            addr,
+            cert: tls_fixture("server.pem"),
+            key: tls_fixture("server.key"),
+            client_ca: tls_fixture("ca.pem"),
+        };
+        let tls = tls_config(&tcp).expect("fixtures should load");
+        let interceptor = AclInterceptor::new(service.acl.clone());
+        tokio::spawn(
+            Server::builder()
+                .tls_config(tls)
+                .expect("TLS should be configured")
+                .layer(
+                    ServiceBuilder::new()
+                        .layer(MapRequestLayer::new(tag_rpc))
+                        .into_inner(),
+                )
+                .add_service(SignerServer::with_interceptor(
+                    service,
+                    interceptor,
+                ))
+                .serve_with_incoming(incoming),
+        );
+
+        addr
+    }
+
+    /// Connect to a service over TLS, with the client certificate and key
+    /// of the given name if any
+    async fn connect_tls(
+        addr: std::net::SocketAddr,
+        client: Option<&str>,
+    ) -> Result<SignerClient<Channel>, tonic::transport::Error> {
+        let ca = read(tls_fixture("ca.pem")).expect("fixture should load");
+        let mut tls = ClientTlsConfig::new()
+            .ca_certificate(Certificate::from_pem(ca))
+            .domain_name("localhost");
+        if let Some(name) = client {
+            let cert = read(tls_fixture(&format!("{}.pem", name)));
+            let key = read(tls_fixture(&format!("{}.key", name)));
+            tls = tls.identity(Identity::from_pem(
+                cert.expect("fixture should load"),
+                key.expect("fixture should load"),
+            ));
+        }
+
+        let channel = Channel::from_shared(format!("https://{}", addr))
+            .expect("valid URI")
+            .tls_config(tls)?
+            .connect()
+            .await?;
+        Ok(SignerClient::new(channel))
+    }
+
+    #[tokio::test]
+    async fn tls_authenticates_clients() -> Result<(), CliError> {
+        // anyone may only list the keys, which TCP clients match
+        let service = MySign {
+            acl: Arc::new(RwLock::new(Acl::new(vec![acl::Rule {
+                uid: None,
+                gid: None,
+                rpcs: Some(vec!["ListKeys".to_string()]),
+                keys: None,
+            }]))),
+            ..MySign::default()
+        };
+        let addr = spawn_tls_service(service).await;
+
+        let mut client = connect_tls(addr, Some("client"))
+            .await
+            .expect("client with a certificate of the CA should connect");
+        let keys = client.list_keys(ListKeysRequest {}).await?.into_inner();
+        assert!(keys.public_keys.is_empty());
+        assert!(denied(client.generate_keys(GenerateKeysRequest {}).await));
+
+        // clients without a certificate, or with one of another CA, are
+        // refused by the handshake
+        for name in [None, Some("other-client")] {
+            let res = match connect_tls(addr, name).await {
+                Ok(mut client) => client.list_keys(ListKeysRequest {}).await,
+                Err(_) => continue,
+            };
+            assert!(res.is_err(), "client {:?} should be refused", name);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cli_end_to_end() -> Result<(), CliError> {
+        let socket = spawn_service("cli", MySign::default(), Arc::default());
+        let mut client = remote::connect(socket.clone()).await?;
+        let hex = Encoding::Hex;
+
+        let keys = remote::run(&mut client, &Command::Gen, hex).await?;
+        let (sk, pk) = (bytes(&keys, "secret_key"), bytes(&keys, "public_key"));
+        let keys = remote::run(&mut client, &Command::Gen, hex).await?;
+        let (sk2, pk2) =
+            (bytes(&keys, "secret_key"), bytes(&keys, "public_key"));
+        let message = hex::encode(b"message");
+
+        let sign = |secret_key: &str, public_key: &str| Command::Sign {
+            secret_key: secret_key.into(),
+            public_key: public_key.into(),
+            message: message.clone(),
+        };
+        let sig = remote::run(&mut client, &sign(&sk, &pk), hex).await?;
+        let sig = bytes(&sig, "signature");
+        let sig2 = remote::run(&mut client, &sign(&sk2, &pk2), hex).await?;
+        let sig2 = bytes(&sig2, "signature");
+
+        let apk = Command::CreateApk {
+            public_key: pk.clone(),
+        };
+        let apk = bytes(&remote::run(&mut client, &apk, hex).await?, "apk");
+
+        let verify =
+            |apk: &str, signature: &str, message: &str| Command::Verify {
+                apk: apk.into(),
+                signature: signature.into(),
+                message: message.into(),
+            };
+        let valid =
+            remote::run(&mut client, &verify(&apk, &sig, &message), hex)
+                .await?
+                .to_json(hex);
+        assert_eq!(valid["valid"], true);
+
+        let other = hex::encode(b"other message");
+        let valid = remote::run(&mut client, &verify(&apk, &sig, &other), hex)
+            .await?
+            .to_json(hex);
+        assert_eq!(valid["valid"], false);
+
+        // aggregate the second key and signature into the first ones
+        let aggregate_pk = Command::AggregatePk {
+            apk: apk.clone(),
+            public_keys: vec![pk2],
+        };
+        let apk =
+            bytes(&remote::run(&mut client, &aggregate_pk, hex).await?, "apk");
+        let aggregate_sig = Command::AggregateSig {
+            signature: sig,
+            signatures: vec![sig2],
+        };
+        let sig = bytes(
+            &remote::run(&mut client, &aggregate_sig, hex).await?,
+            "signature",
+        );
+        let valid =
+            remote::run(&mut client, &verify(&apk, &sig, &message), hex)
+                .await?
+                .to_json(hex);
+        assert_eq!(valid["valid"], true);
+
+        // malformed keys are reported with the error of the service
+        let res = remote::run(&mut client, &sign("00", &pk), hex).await;
+        assert!(
+            matches!(res, Err(CliError::Failed(e)) if e == "BlsInvalidBytes")
+        );
+
+        let _ = remove_file(&socket);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn acl_restricts_rpcs_and_keys() -> Result<(), CliError> {
+        let mut keystore = Keystore::default();
+        let allowed = keystore.insert(SecretKey::random(&mut rand_core::OsRng));
+        let other = keystore.insert(SecretKey::random(&mut rand_core::OsRng));
+        let allowed = allowed.to_bytes().to_vec();
+        let unknown = vec![0; PublicKey::SIZE];
+
+        // SAFETY: getuid never fails
+        let uid = unsafe { libc::getuid() };
+        let rule = |uid, rpcs: &[&str]| acl::Rule {
+            uid: Some(uid),
+            gid: None,
+            rpcs: Some(rpcs.iter().map(|rpc| rpc.to_string()).collect()),
+            keys: Some(vec![allowed.clone(), unknown.clone()]),
+        };
+        let service = MySign {
+            keystore: Arc::new(RwLock::new(keystore)),
+            acl: Arc::new(RwLock::new(Acl::new(vec![
+                rule(uid, &["SignWithKeyId", "BatchSign", "ListKeys"]),
+                rule(uid.wrapping_add(1), &["Sign"]),
+            ]))),
+            ..MySign::default()
+        };
+        let socket = spawn_service("acl", service.clone(), Arc::default());
+        let mut client = remote::connect(socket.clone()).await?;
+
+        let keys = client.list_keys(ListKeysRequest {}).await?.into_inner();
+        assert_eq!(keys.public_keys, vec![allowed.clone()]);
+
+        let sign = |key_id: Vec<u8>| SignWithKeyIdRequest {
+            key_id,
+            message: b"message".to_vec(),
+        };
+        let res = client
+            .sign_with_key_id(sign(allowed.clone()))
+            .await?
+            .into_inner();
+        assert!(matches!(res.sig, Some(Sig::Signature(_))));
+
+        // unknown keys fail on their own, while keys that aren't allowed deny
+        // the whole batch
+        let batch = |key_ids: &[&Vec<u8>]| BatchSignRequest {
+            items: key_ids.iter().map(|&key_id| sign(key_id.clone())).collect(),
+        };
+        let res = client
+            .batch_sign(batch(&[&allowed, &unknown]))
+            .await?
+            .into_inner();
+        assert!(matches!(res.results[0].sig, Some(Sig::Signature(_))));
+        assert_eq!(
+            res.results[1].sig,
+            Some(Sig::Error(Error::UnknownKeyId as i32))
+        );
+
+        let other = other.to_bytes().to_vec();
+        assert!(denied(client.sign_with_key_id(sign(other.clone())).await));
+        assert!(denied(client.batch_sign(batch(&[&allowed, &other])).await));
+        assert!(denied(client.generate_keys(GenerateKeysRequest {}).await));
+
+        // clients matching no rule are denied everything
+        *service.acl.write().unwrap() =
+            Acl::new(vec![rule(uid.wrapping_add(1), &["GenerateKeys"])]);
+        assert!(denied(client.generate_keys(GenerateKeysRequest {}).await));
+
+        let _ = remove_file(&socket);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn drain_gives_up_after_timeout() {
+        let (tx, rx) = watch::channel(false);
+        let timeout = Duration::from_millis(50);
+
+        let done = drain(future::ready(()), rx.clone(), timeout).await;
+        assert_eq!(done, Some(()));
+
+        // servers are waited for as long as the shutdown isn't signaled
+        let waiting = drain(future::pending::<()>(), rx.clone(), timeout);
+        let res = tokio::time::timeout(timeout * 4, waiting).await;
+        assert!(res.is_err());
+
+        tx.send(true).expect("receivers are alive");
+        let stuck = drain(future::pending::<()>(), rx, timeout).await;
+        assert_eq!(stuck, None);
+    }
+
+    #[tokio::test]
+    async fn metrics_are_scraped() -> Result<(), Box<dyn std::error::Error>> {
+        let metrics = Arc::new(Metrics::default());
+        let socket =
+            spawn_service("metrics", MySign::default(), metrics.clone());
+        let mut client = remote::connect(socket.clone()).await?;
+
+        client.generate_keys(GenerateKeysRequest {}).await?;
+        let invalid = VerifyRequest {
+            apk: vec![],
+            signature: vec![],
+            message: vec![],
+        };
+        client.verify(invalid).await?;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let (_shutdown, rx) = watch::channel(false);
+        tokio::spawn(metrics::serve(listener, metrics, shutdown(rx)));
+
+        let scrape = tokio::task::spawn_blocking(move || {
+            let mut stream = std::net::TcpStream::connect(addr)?;
+            stream.write_all(b"GET /metrics HTTP/1.0\r\n\r\n")?;
+            let mut response = String::new();
+            stream.read_to_string(&mut response)?;
+            std::io::Result::Ok(response)
+        });
+        let response = scrape.await??;
+
+        assert!(response.starts_with("HTTP/1.0 200 OK"));
+        for line in [
+            r#"bls12381svc_requests_total{method="GenerateKeys",code="0"} 1"#,
+            r#"bls12381svc_requests_total{method="Verify",code="0"} 1"#,
+            r#"bls12381svc_errors_total{method="Verify",error="BlsInvalidBytes"} 1"#,
+        ] {
+            assert!(response.contains(line), "missing {}", line);
+        }
+        assert!(!response.contains("signer.Signer"));
+
+        let _ = remove_file(&socket);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn socket_permissions() -> std::io::Result<()> {
+        let socket = std::env::temp_dir()
+            .join(format!("bls12381svc-mode-{}.sock", std::process::id()));
+        let _ = remove_file(&socket);
+
+        let mode = |path| {
+            std::fs::metadata(path).map(|m| m.permissions().mode() & 0o777)
+        };
+
+        let _uds = unix::bind(&socket, Some(0o660))?;
+        assert_eq!(mode(&socket)?, 0o660);
+        remove_file(&socket)?;
+
+        // SAFETY: umask never fails
+        let umask = unsafe { libc::umask(0o022) };
+        let uds = unix::bind(&socket, None);
+        unsafe { libc::umask(umask) };
+        drop(uds?);
+        assert_eq!(mode(&socket)?, 0o755);
+
+        remove_file(&socket)
+    }
+
+    #[test]
+    fn keystore_refuses_readable_keys() -> std::io::Result<()> {
+        let dir = std::env::temp_dir()
+            .join(format!("bls12381svc-keystore-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir(&dir)?;
+
+        let sk = SecretKey::random(&mut rand_core::OsRng);
+        let path = dir.join("signer.key");
+        // the key is written to a file created private, as an operator would
+        let mut file = std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .mode(0o600)
+            .open(&path)?;
+        write!(file, "{}", hex::encode(sk.to_bytes()))?;
+
+        for mode in [0o644, 0o640, 0o604, 0o620] {
+            set_permissions(&path, Permissions::from_mode(mode))?;
+            let err = Keystore::load(&dir).err().expect("key file refused");
+            assert_eq!(err.kind(), std::io::ErrorKind::PermissionDenied);
+        }
+
+        set_permissions(&path, Permissions::from_mode(0o600))?;
+        assert_eq!(Keystore::load(&dir)?.len(), 1);
+
+        std::fs::remove_dir_all(&dir)
+    }
+
+    #[tokio::test]
+    async fn limits_reject_large_requests() -> Result<(), CliError> {
+        let service = MySign {
+            max_list_length: 2,
+            ..MySign::default()
+        };
+        let socket = spawn_service("limits", service, Arc::default());
+        let mut client = remote::connect(socket.clone()).await?;
+
+        let sk = SecretKey::random(&mut rand_core::OsRng);
+        let pk = PublicKey::from(&sk);
+        let apk = APK::from(&pk).to_bytes().to_vec();
+        let pk = pk.to_bytes().to_vec();
+
+        let aggregate = |n| AggregatePkRequest {
+            apk: apk.clone(),
+            keys: vec![pk.clone(); n],
+        };
+        let res = client.aggregate_pk(aggregate(2)).await?.into_inner();
+        assert!(matches!(res.agg, Some(Agg::Code(_))));
+        let exhausted = tonic::Code::ResourceExhausted;
+        assert!(failed_with(
+            client.aggregate_pk(aggregate(3)).await,
+            exhausted
+        ));
+
+        let verify = |len| VerifyRequest {
+            apk: apk.clone(),
+            signature: vec![],
+            message: vec![0; len],
+        };
+        let res = client.verify(verify(1024)).await?.into_inner();
+        assert!(matches!(res.ver, Some(Ver::Error(_))));
+        let res = client.verify(verify(MAX_MESSAGE_SIZE)).await;
+        assert!(failed_with(res, exhausted));
+
+        // the connection is still usable after a rejected message
+        let res = client.verify(verify(1024)).await?.into_inner();
+        assert!(matches!(res.ver, Some(Ver::Error(_))));
+
+        let _ = remove_file(&socket);
+        Ok(())
+    }
+}
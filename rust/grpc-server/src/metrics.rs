@@ -0,0 +1,516 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use {
+    crate::acl::RPCS,
+    dusk_bls12_381_sign_ipc::proto::Error,
+    futures::{ready, Future},
+    hyper::body::{HttpBody, SizeHint},
+    hyper::header::{HeaderMap, HeaderValue, CONTENT_TYPE},
+    hyper::service::{make_service_fn, service_fn},
+    hyper::{Body, StatusCode},
+    std::collections::BTreeMap,
+    std::convert::Infallible,
+    std::fmt::Write,
+    std::net::TcpListener,
+    std::pin::Pin,
+    std::sync::{Arc, Mutex, PoisonError},
+    std::task::{Context, Poll},
+    std::time::{Duration, Instant},
+    tonic::{Code, Response, Status},
+    tower::{Layer, Service},
+};
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Upper bounds of the buckets of the request durations, in seconds.
+const DURATION_BUCKETS: &[f64] = &[
+    0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0,
+];
+
+/// Upper bounds of the buckets of the batch sizes.
+const BATCH_SIZE_BUCKETS: &[f64] = &[
+    1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0, 128.0, 256.0, 512.0, 1024.0,
+];
+
+/// Methods of the health and reflection services.
+const SERVICE_METHODS: &[&str] = &[
+    "grpc.health.v1.Health/Check",
+    "grpc.health.v1.Health/Watch",
+    "grpc.reflection.v1alpha.ServerReflection/ServerReflectionInfo",
+];
+
+/// Label of the method of a request path: the name of the RPC for the
+/// `Signer` service, the path for the health and reflection services, and
+/// `unknown` for anything else, so that clients can't add labels at will.
+fn method_label(path: &str) -> &'static str {
+    let path = path.trim_start_matches('/');
+    let rpc = path
+        .strip_prefix("signer.Signer/")
+        .and_then(|rpc| RPCS.iter().find(|name| **name == rpc));
+    let method = SERVICE_METHODS.iter().find(|method| **method == path);
+
+    rpc.or(method).copied().unwrap_or("unknown")
+}
+
+/// Outcome of a call, attached to its response by the handlers for the
+/// [`MetricsLayer`] to record.
+#[derive(Debug, Default)]
+pub struct Outcome {
+    /// Codes of the errors returned in the response
+    pub errors: Vec<i32>,
+    /// Number of items of a batch call
+    pub batch_size: Option<usize>,
+}
+
+/// Responses reporting the outcome of their call.
+pub trait Observe {
+    fn outcome(&self) -> Outcome {
+        Outcome::default()
+    }
+}
+
+/// Wrap a message in a response, attaching its outcome for the
+/// [`MetricsLayer`].
+pub fn respond<T: Observe>(message: T) -> Response<T> {
+    let outcome = message.outcome();
+    let mut response = Response::new(message);
+    response.extensions_mut().insert(outcome);
+    response
+}
+
+#[derive(Debug)]
+struct Histogram {
+    bounds: &'static [f64],
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(bounds: &'static [f64]) -> Self {
+        Self {
+            bounds,
+            counts: vec![0; bounds.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        if let Some(i) = self.bounds.iter().position(|b| value <= *b) {
+            self.counts[i] += 1;
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+
+    fn render(&self, out: &mut String, name: &str, labels: &str) {
+        let mut cumulative = 0;
+        for (bound, count) in self.bounds.iter().zip(&self.counts) {
+            cumulative += count;
+            let _ = writeln!(
+                out,
+                "{}_bucket{{{},le=\"{}\"}} {}",
+                name, labels, bound, cumulative
+            );
+        }
+        let _ = writeln!(
+            out,
+            "{}_bucket{{{},le=\"+Inf\"}} {}",
+            name, labels, self.count
+        );
+        let _ = writeln!(out, "{}_sum{{{}}} {}", name, labels, self.sum);
+        let _ = writeln!(out, "{}_count{{{}}} {}", name, labels, self.count);
+    }
+}
+
+#[derive(Debug, Default)]
+struct Registry {
+    /// Requests by method and gRPC status code
+    requests: BTreeMap<(String, String), u64>,
+    /// Errors returned in the responses, by method and error
+    errors: BTreeMap<(String, String), u64>,
+    durations: BTreeMap<String, Histogram>,
+    batch_sizes: BTreeMap<String, Histogram>,
+}
+
+/// Metrics of the calls to the server.
+#[derive(Debug, Default)]
+pub struct Metrics(Mutex<Registry>);
+
+impl Metrics {
+    fn record(
+        &self,
+        method: &str,
+        code: &str,
+        duration: Duration,
+        outcome: Option<&Outcome>,
+    ) {
+        let mut registry =
+            self.0.lock().unwrap_or_else(PoisonError::into_inner);
+
+        *registry
+            .requests
+            .entry((method.into(), code.into()))
+            .or_default() += 1;
+        registry
+            .durations
+            .entry(method.into())
+            .or_insert_with(|| Histogram::new(DURATION_BUCKETS))
+            .observe(duration.as_secs_f64());
+
+        if let Some(outcome) = outcome {
+            for code in &outcome.errors {
+                let error = match Error::from_i32(*code) {
+                    Some(error) => format!("{:?}", error),
+                    None => code.to_string(),
+                };
+                *registry.errors.entry((method.into(), error)).or_default() +=
+                    1;
+            }
+            if let Some(size) = outcome.batch_size {
+                registry
+                    .batch_sizes
+                    .entry(method.into())
+                    .or_insert_with(|| Histogram::new(BATCH_SIZE_BUCKETS))
+                    .observe(size as f64);
+            }
+        }
+    }
+
+    /// Render the metrics in the Prometheus text format.
+    pub fn render(&self) -> String {
+        let registry = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut out = String::new();
+
+        out.push_str(
+            "# HELP bls12381svc_requests_total Requests by method and gRPC status code.\n\
+             # TYPE bls12381svc_requests_total counter\n",
+        );
+        for ((method, code), count) in &registry.requests {
+            let _ = writeln!(
+                out,
+                "bls12381svc_requests_total{{method=\"{}\",code=\"{}\"}} {}",
+                method, code, count
+            );
+        }
+
+        out.push_str(
+            "# HELP bls12381svc_request_duration_seconds Time to complete requests by method.\n\
+             # TYPE bls12381svc_request_duration_seconds histogram\n",
+        );
+        for (method, histogram) in &registry.durations {
+            histogram.render(
+                &mut out,
+                "bls12381svc_request_duration_seconds",
+                &format!("method=\"{}\"", method),
+            );
+        }
+
+        out.push_str(
+            "# HELP bls12381svc_errors_total Errors returned in responses by method and error.\n\
+             # TYPE bls12381svc_errors_total counter\n",
+        );
+        for ((method, error), count) in &registry.errors {
+            let _ = writeln!(
+                out,
+                "bls12381svc_errors_total{{method=\"{}\",error=\"{}\"}} {}",
+                method, error, count
+            );
+        }
+
+        out.push_str(
+            "# HELP bls12381svc_batch_size Items of batch requests by method.\n\
+             # TYPE bls12381svc_batch_size histogram\n",
+        );
+        for (method, histogram) in &registry.batch_sizes {
+            histogram.render(
+                &mut out,
+                "bls12381svc_batch_size",
+                &format!("method=\"{}\"", method),
+            );
+        }
+
+        out
+    }
+}
+
+/// Tower layer recording the [`Metrics`] of every call to the server.
+#[derive(Debug, Clone)]
+pub struct MetricsLayer(Arc<Metrics>);
+
+impl MetricsLayer {
+    pub fn new(metrics: Arc<Metrics>) -> Self {
+        Self(metrics)
+    }
+}
+
+impl<S> Layer<S> for MetricsLayer {
+    type Service = MetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MetricsService {
+            inner,
+            metrics: self.0.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MetricsService<S> {
+    inner: S,
+    metrics: Arc<Metrics>,
+}
+
+impl<S, ReqBody, ResBody> Service<hyper::Request<ReqBody>> for MetricsService<S>
+where
+    S: Service<hyper::Request<ReqBody>, Response = hyper::Response<ResBody>>,
+    S::Error: Into<BoxError>,
+    S::Future: Send + 'static,
+{
+    type Response = hyper::Response<MetricsBody<ResBody>>;
+    type Error = BoxError;
+    type Future = Pin<
+        Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, request: hyper::Request<ReqBody>) -> Self::Future {
+        let mut call = Call {
+            metrics: self.metrics.clone(),
+            method: method_label(request.uri().path()),
+            start: Instant::now(),
+            code: None,
+            outcome: None,
+        };
+        let response = self.inner.call(request);
+
+        Box::pin(async move {
+            let mut response = match response.await {
+                Ok(response) => response,
+                // calls rejected by an interceptor fail with their status,
+                // which is turned into a response further out
+                Err(e) => {
+                    let e = e.into();
+                    let code = match e.downcast_ref::<Status>() {
+                        Some(status) => status.code(),
+                        None => Code::Unknown,
+                    };
+                    call.set_code(Some((code as i32).to_string()));
+                    return Err(e);
+                }
+            };
+
+            // a status other than OK is sent in the headers, as a response
+            // without a body, while OK and the errors of streams are only
+            // sent in the trailers, once the body is complete
+            call.set_code(grpc_status(response.headers()));
+            call.outcome = response.extensions_mut().remove::<Outcome>();
+
+            Ok(response.map(|inner| MetricsBody {
+                inner,
+                call: Some(call),
+            }))
+        })
+    }
+}
+
+/// Status code of a call, as sent in the headers or the trailers of its
+/// response.
+fn grpc_status(headers: &HeaderMap) -> Option<String> {
+    let code = headers.get("grpc-status")?.to_str().ok()?;
+    Some(code.into())
+}
+
+/// Call in progress, recorded in the [`Metrics`] once it's dropped.
+struct Call {
+    metrics: Arc<Metrics>,
+    method: &'static str,
+    start: Instant,
+    code: Option<String>,
+    outcome: Option<Outcome>,
+}
+
+impl Call {
+    /// Set the status code of the call, if sent.
+    fn set_code(&mut self, code: Option<String>) {
+        if code.is_some() {
+            self.code = code;
+        }
+    }
+}
+
+impl Drop for Call {
+    fn drop(&mut self) {
+        // a response dropped before sending its status was cancelled, such
+        // as a stream the client went away from
+        let code = self
+            .code
+            .take()
+            .unwrap_or_else(|| (Code::Cancelled as i32).to_string());
+        self.metrics.record(
+            self.method,
+            &code,
+            self.start.elapsed(),
+            self.outcome.as_ref(),
+        );
+    }
+}
+
+/// Body of a response, recording its call once the trailers are sent, or the
+/// body is dropped, so that streaming calls are recorded when they complete.
+pub struct MetricsBody<B> {
+    inner: B,
+    call: Option<Call>,
+}
+
+impl<B: HttpBody + Unpin> HttpBody for MetricsBody<B> {
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_data(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        Pin::new(&mut self.inner).poll_data(cx)
+    }
+
+    fn poll_trailers(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<HeaderMap>, Self::Error>> {
+        let trailers = ready!(Pin::new(&mut self.inner).poll_trailers(cx));
+
+        if let Some(mut call) = self.call.take() {
+            let code = match &trailers {
+                Ok(Some(trailers)) => grpc_status(trailers),
+                Ok(None) => None,
+                Err(_) => Some((Code::Unknown as i32).to_string()),
+            };
+            call.set_code(code);
+        }
+
+        Poll::Ready(trailers)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+fn metrics_response(
+    metrics: &Metrics,
+    request: hyper::Request<Body>,
+) -> hyper::Response<Body> {
+    if request.uri().path() != "/metrics" {
+        let mut response = hyper::Response::new(Body::empty());
+        *response.status_mut() = StatusCode::NOT_FOUND;
+        return response;
+    }
+
+    let mut response = hyper::Response::new(Body::from(metrics.render()));
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static("text/plain; version=0.0.4"),
+    );
+    response
+}
+
+/// Serve the metrics over HTTP at `/metrics` on `listener`, until `shutdown`
+/// resolves.
+pub async fn serve<F: Future<Output = ()>>(
+    listener: TcpListener,
+    metrics: Arc<Metrics>,
+    shutdown: F,
+) -> hyper::Result<()> {
+    let make_service = make_service_fn(move |_| {
+        let metrics = metrics.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let response = metrics_response(&metrics, request);
+                async move { Ok::<_, Infallible>(response) }
+            }))
+        }
+    });
+
+    hyper::Server::from_tcp(listener)?
+        .serve(make_service)
+        .with_graceful_shutdown(shutdown)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::service_fn;
+
+    fn requests(metrics: &Metrics) -> Vec<(String, String)> {
+        let registry = metrics.0.lock().unwrap();
+        registry.requests.keys().cloned().collect()
+    }
+
+    #[tokio::test]
+    async fn streams_are_recorded_when_complete() {
+        let metrics = Arc::new(Metrics::default());
+        let (mut tx, body) = Body::channel();
+        let mut body = Some(body);
+        let mut service = MetricsLayer::new(metrics.clone()).layer(service_fn(
+            move |_: hyper::Request<Body>| {
+                let body = body.take().expect("called once");
+                async move { Ok::<_, Infallible>(hyper::Response::new(body)) }
+            },
+        ));
+
+        let request = hyper::Request::builder()
+            .uri("/signer.Signer/VerifyStream")
+            .body(Body::empty())
+            .unwrap();
+        let mut response = service.call(request).await.unwrap();
+        assert!(requests(&metrics).is_empty());
+
+        // the status of the stream is sent in the trailers, after its
+        // messages
+        tokio::spawn(async move {
+            tx.send_data("message".into()).await?;
+            let mut trailers = HeaderMap::new();
+            trailers.insert("grpc-status", HeaderValue::from_static("13"));
+            tx.send_trailers(trailers).await
+        });
+
+        assert!(response.body_mut().data().await.is_some());
+        assert!(requests(&metrics).is_empty());
+        assert!(response.body_mut().trailers().await.unwrap().is_some());
+        assert_eq!(
+            requests(&metrics),
+            vec![("VerifyStream".to_string(), "13".to_string())]
+        );
+    }
+
+    #[test]
+    fn methods_have_fixed_labels() {
+        assert_eq!(method_label("/signer.Signer/Sign"), "Sign");
+        assert_eq!(method_label("/signer.Signer/VerifyStream"), "VerifyStream");
+        assert_eq!(
+            method_label("/grpc.health.v1.Health/Check"),
+            "grpc.health.v1.Health/Check"
+        );
+
+        for path in ["/signer.Signer/Unknown", "/other.Service/Sign", "/", ""] {
+            assert_eq!(method_label(path), "unknown");
+        }
+    }
+}
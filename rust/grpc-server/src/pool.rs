@@ -0,0 +1,87 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use {
+    std::sync::Arc,
+    tokio::{sync::Semaphore, task},
+    tonic::Status,
+};
+
+/// Pool running the CPU-bound work of the calls, such as pairings and
+/// signing, on the blocking threads of the runtime, so that it doesn't stall
+/// the reactor. At most as many jobs as the pool has workers run at once,
+/// the others waiting for their turn.
+#[derive(Debug, Clone)]
+pub struct WorkPool {
+    permits: Arc<Semaphore>,
+}
+
+impl WorkPool {
+    pub fn new(workers: usize) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(workers)),
+        }
+    }
+
+    /// Run a job on the pool, once a worker is available. The worker is busy
+    /// until the job completes, even if the call is cancelled before.
+    pub async fn run<F, T>(&self, job: F) -> Result<T, Status>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let permit = self
+            .permits
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|_| Status::unavailable("work pool closed"))?;
+
+        task::spawn_blocking(move || {
+            let _permit = permit;
+            job()
+        })
+        .await
+        .map_err(|e| Status::internal(format!("job failed: {}", e)))
+    }
+}
+
+impl Default for WorkPool {
+    /// A pool with a worker per available CPU.
+    fn default() -> Self {
+        Self::new(default_workers())
+    }
+}
+
+/// Number of CPUs available to the service.
+pub fn default_workers() -> usize {
+    std::thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    #[tokio::test]
+    async fn cancelled_jobs_hold_their_worker() {
+        let pool = WorkPool::new(1);
+        let (tx, rx) = mpsc::channel::<()>();
+
+        // the call gives up on a job that keeps running
+        let job = pool.run(move || rx.recv());
+        assert!(timeout(Duration::from_millis(50), job).await.is_err());
+
+        let next = pool.run(|| ());
+        tokio::pin!(next);
+        assert!(timeout(Duration::from_millis(50), &mut next).await.is_err());
+
+        drop(tx);
+        assert!(timeout(Duration::from_secs(5), next).await.is_ok());
+    }
+}
@@ -0,0 +1,78 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Messages and services of the signer protocol, and the conversions of the
+//! results of the library into its responses.
+
+tonic::include_proto!("signer");
+
+use {
+    aggregate_response::Agg,
+    create_apk_response::Apk,
+    dusk_bls12_381_sign::{Error as BlsError, Signature, APK},
+    dusk_bytes::Serializable,
+    sign_response::Sig,
+    verify_response::Ver,
+};
+
+impl From<BlsError> for Error {
+    fn from(err: BlsError) -> Self {
+        match err {
+            BlsError::BytesError(_) => Error::BlsInvalidBytes,
+            BlsError::InvalidSignature => Error::BlsVerificationFailed,
+            BlsError::InvalidThreshold => Error::BlsInvalidThreshold,
+            BlsError::InvalidShares => Error::BlsInvalidShares,
+            BlsError::InvalidCiphertext => Error::BlsInvalidCiphertext,
+            BlsError::NotYetValid => Error::BlsNotYetValid,
+            BlsError::Expired => Error::BlsExpired,
+            BlsError::Replay => Error::BlsReplay,
+            BlsError::DoubleSign => Error::BlsDoubleSign,
+            BlsError::Storage => Error::BlsStorage,
+            BlsError::InvalidInterchange => Error::BlsInvalidInterchange,
+            _ => Error::Unknown,
+        }
+    }
+}
+
+impl From<Result<Signature, Error>> for SignResponse {
+    fn from(res: Result<Signature, Error>) -> Self {
+        let sig = match res {
+            Ok(sig) => Sig::Signature(sig.to_bytes().to_vec()),
+            Err(err) => Sig::Error(err as i32),
+        };
+        Self { sig: Some(sig) }
+    }
+}
+
+impl From<Result<(), Error>> for VerifyResponse {
+    fn from(res: Result<(), Error>) -> Self {
+        let ver = match res {
+            Ok(()) => Ver::Valid(true),
+            Err(err) => Ver::Error(err as i32),
+        };
+        Self { ver: Some(ver) }
+    }
+}
+
+impl From<Result<APK, Error>> for CreateApkResponse {
+    fn from(res: Result<APK, Error>) -> Self {
+        let apk = match res {
+            Ok(apk) => Apk::Apk(apk.to_bytes().to_vec()),
+            Err(err) => Apk::Error(err as i32),
+        };
+        Self { apk: Some(apk) }
+    }
+}
+
+impl From<Result<Vec<u8>, Error>> for AggregateResponse {
+    fn from(res: Result<Vec<u8>, Error>) -> Self {
+        let agg = match res {
+            Ok(code) => Agg::Code(code),
+            Err(err) => Agg::Error(err as i32),
+        };
+        Self { agg: Some(agg) }
+    }
+}
@@ -0,0 +1,226 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#[allow(clippy::enum_variant_names)]
+mod proto {
+    tonic::include_proto!("grpc.reflection.v1alpha");
+}
+
+pub use proto::server_reflection_server::ServerReflectionServer;
+
+use {
+    futures::Stream,
+    prost::Message,
+    prost_types::{DescriptorProto, FileDescriptorProto, FileDescriptorSet},
+    proto::{
+        server_reflection_request::MessageRequest,
+        server_reflection_response::MessageResponse,
+        server_reflection_server::ServerReflection, ErrorResponse,
+        ExtensionNumberResponse, FileDescriptorResponse, ListServiceResponse,
+        ServerReflectionRequest, ServerReflectionResponse, ServiceResponse,
+    },
+    std::pin::Pin,
+    std::sync::Arc,
+    tonic::{Code, Request, Response, Status, Streaming},
+};
+
+/// Descriptors of the protos served by the service, written by the build
+/// script.
+const FILE_DESCRIPTOR_SET: &[u8] =
+    include_bytes!(concat!(env!("OUT_DIR"), "/bls12381svc_descriptor.bin"));
+
+/// Implementation of the standard `grpc.reflection.v1alpha.ServerReflection`
+/// service, describing the services of the server to clients such as
+/// `grpcurl`.
+#[derive(Clone)]
+pub struct ReflectionService {
+    files: Arc<Vec<FileDescriptorProto>>,
+}
+
+/// Create a reflection service describing the protos of the server.
+pub fn reflection_service(
+) -> Result<ServerReflectionServer<ReflectionService>, prost::DecodeError> {
+    let set = FileDescriptorSet::decode(FILE_DESCRIPTOR_SET)?;
+    let service = ReflectionService {
+        files: Arc::new(set.file),
+    };
+
+    Ok(ServerReflectionServer::new(service))
+}
+
+/// Whether a message, or a type nested in it, is named `symbol` relative to
+/// the scope the message is in.
+fn message_defines(msg: &DescriptorProto, symbol: &str) -> bool {
+    if msg.name() == symbol {
+        return true;
+    }
+
+    match symbol
+        .strip_prefix(msg.name())
+        .and_then(|s| s.strip_prefix('.'))
+    {
+        Some(nested) => {
+            msg.nested_type.iter().any(|m| message_defines(m, nested))
+                || msg.enum_type.iter().any(|e| e.name() == nested)
+        }
+        None => false,
+    }
+}
+
+/// Whether a file defines the fully-qualified `symbol`, as a message, an
+/// enum, a service or a method.
+fn file_defines(file: &FileDescriptorProto, symbol: &str) -> bool {
+    let symbol = match file.package() {
+        "" => symbol,
+        package => match symbol
+            .strip_prefix(package)
+            .and_then(|s| s.strip_prefix('.'))
+        {
+            Some(symbol) => symbol,
+            None => return false,
+        },
+    };
+
+    file.message_type.iter().any(|m| message_defines(m, symbol))
+        || file.enum_type.iter().any(|e| e.name() == symbol)
+        || file.service.iter().any(|s| {
+            s.name() == symbol
+                || s.method
+                    .iter()
+                    .any(|m| symbol == format!("{}.{}", s.name(), m.name()))
+        })
+}
+
+fn error(code: Code, message: &str) -> MessageResponse {
+    MessageResponse::ErrorResponse(ErrorResponse {
+        error_code: code as i32,
+        error_message: message.into(),
+    })
+}
+
+impl ReflectionService {
+    fn file_by_name(&self, name: &str) -> Option<&FileDescriptorProto> {
+        self.files.iter().find(|f| f.name() == name)
+    }
+
+    fn file_containing_symbol(
+        &self,
+        symbol: &str,
+    ) -> Option<&FileDescriptorProto> {
+        self.files.iter().find(|f| file_defines(f, symbol))
+    }
+
+    /// Encode a file followed by the files it depends on, transitively.
+    fn file_descriptors(&self, file: &FileDescriptorProto) -> MessageResponse {
+        let mut files = vec![file];
+        let mut i = 0;
+        while i < files.len() {
+            for dep in &files[i].dependency {
+                if let Some(dep) = self.file_by_name(dep) {
+                    if !files.iter().any(|f| f.name() == dep.name()) {
+                        files.push(dep);
+                    }
+                }
+            }
+            i += 1;
+        }
+
+        MessageResponse::FileDescriptorResponse(FileDescriptorResponse {
+            file_descriptor_proto: files
+                .iter()
+                .map(|f| f.encode_to_vec())
+                .collect(),
+        })
+    }
+
+    fn list_services(&self) -> MessageResponse {
+        let service = self
+            .files
+            .iter()
+            .flat_map(|f| {
+                f.service.iter().map(move |s| ServiceResponse {
+                    name: match f.package() {
+                        "" => s.name().into(),
+                        package => format!("{}.{}", package, s.name()),
+                    },
+                })
+            })
+            .collect();
+
+        MessageResponse::ListServicesResponse(ListServiceResponse { service })
+    }
+
+    fn respond(&self, request: &MessageRequest) -> MessageResponse {
+        match request {
+            MessageRequest::FileByFilename(name) => {
+                match self.file_by_name(name) {
+                    Some(file) => self.file_descriptors(file),
+                    None => error(Code::NotFound, "unknown file"),
+                }
+            }
+            MessageRequest::FileContainingSymbol(symbol) => {
+                match self.file_containing_symbol(symbol) {
+                    Some(file) => self.file_descriptors(file),
+                    None => error(Code::NotFound, "unknown symbol"),
+                }
+            }
+            // the protos of the server are proto3, which has no extensions
+            MessageRequest::FileContainingExtension(_) => {
+                error(Code::NotFound, "unknown extension")
+            }
+            MessageRequest::AllExtensionNumbersOfType(ty) => {
+                match self.file_containing_symbol(ty) {
+                    Some(_) => MessageResponse::AllExtensionNumbersResponse(
+                        ExtensionNumberResponse {
+                            base_type_name: ty.clone(),
+                            extension_number: vec![],
+                        },
+                    ),
+                    None => error(Code::NotFound, "unknown type"),
+                }
+            }
+            MessageRequest::ListServices(_) => self.list_services(),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ServerReflection for ReflectionService {
+    type ServerReflectionInfoStream = Pin<
+        Box<
+            dyn Stream<Item = Result<ServerReflectionResponse, Status>>
+                + Send
+                + Sync
+                + 'static,
+        >,
+    >;
+
+    /// Answer a stream of reflection requests, in order
+    async fn server_reflection_info(
+        &self,
+        request: Request<Streaming<ServerReflectionRequest>>,
+    ) -> Result<Response<Self::ServerReflectionInfoStream>, Status> {
+        let mut requests = request.into_inner();
+        let service = self.clone();
+
+        let responses = async_stream::try_stream! {
+            while let Some(req) = requests.message().await? {
+                let message_response = match &req.message_request {
+                    Some(request) => service.respond(request),
+                    None => error(Code::InvalidArgument, "empty request"),
+                };
+
+                yield ServerReflectionResponse {
+                    valid_host: req.host.clone(),
+                    original_request: Some(req),
+                    message_response: Some(message_response),
+                };
+            }
+        };
+
+        Ok(Response::new(Box::pin(responses)))
+    }
+}
@@ -0,0 +1,201 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Commands of the client of the signer service.
+
+use {
+    crate::cli::{read_value, CliError, Encoding, Output},
+    crate::proto::{
+        aggregate_response::Agg, create_apk_response::Apk, sign_response::Sig,
+        signer_client::SignerClient, verify_response::Ver, AggregatePkRequest,
+        AggregateResponse, AggregateSigRequest, CreateApkRequest, Error,
+        GenerateKeysRequest, SignRequest, VerifyRequest,
+    },
+    clap::Subcommand,
+    std::error::Error as _,
+    std::path::PathBuf,
+    tokio::net::UnixStream,
+    tonic::transport::{Channel, Endpoint, Uri},
+    tower::service_fn,
+};
+
+/// Commands of the client. Binary values are given with the encoding of
+/// `--encoding`, or as `@PATH` to read the raw bytes of a file, or as `-` to
+/// read the raw bytes of the standard input.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Generate a new key pair
+    Gen,
+    /// Sign a message with a key pair
+    Sign {
+        secret_key: String,
+        public_key: String,
+        message: String,
+    },
+    /// Verify a signature of a message with an aggregated public key
+    Verify {
+        apk: String,
+        signature: String,
+        message: String,
+    },
+    /// Convert a public key into an aggregated public key
+    CreateApk { public_key: String },
+    /// Add public keys to an aggregated public key
+    AggregatePk {
+        apk: String,
+        #[clap(required = true)]
+        public_keys: Vec<String>,
+    },
+    /// Aggregate signatures into a signature
+    AggregateSig {
+        signature: String,
+        #[clap(required = true)]
+        signatures: Vec<String>,
+    },
+}
+
+/// Connect to the service listening on the Unix domain socket at `socket`.
+pub async fn connect(
+    socket: PathBuf,
+) -> Result<SignerClient<Channel>, CliError> {
+    let path = socket.display().to_string();
+
+    // the URI is ignored by the connector, but has to be valid
+    let channel = Endpoint::from_static("http://[::]:50051")
+        .connect_with_connector(service_fn(move |_: Uri| {
+            UnixStream::connect(socket.clone())
+        }))
+        .await
+        .map_err(|e| {
+            let cause =
+                e.source().map_or_else(|| e.to_string(), |s| s.to_string());
+            CliError::Connect(path, cause)
+        })?;
+
+    Ok(SignerClient::new(channel))
+}
+
+fn service_error(code: i32) -> CliError {
+    match Error::from_i32(code) {
+        Some(err) => CliError::Failed(format!("{:?}", err)),
+        None => CliError::Failed(format!("unknown error code {}", code)),
+    }
+}
+
+fn empty_response() -> CliError {
+    CliError::Failed("empty response".into())
+}
+
+fn aggregate_output(
+    res: AggregateResponse,
+    name: &'static str,
+) -> Result<Output, CliError> {
+    match res.agg {
+        Some(Agg::Code(bytes)) => Ok(Output::default().bytes(name, bytes)),
+        Some(Agg::Error(code)) => Err(service_error(code)),
+        None => Err(empty_response()),
+    }
+}
+
+/// Run a command with the service.
+pub async fn run(
+    client: &mut SignerClient<Channel>,
+    command: &Command,
+    encoding: Encoding,
+) -> Result<Output, CliError> {
+    let value = |arg: &str| read_value(arg, encoding);
+    let values = |args: &[String]| {
+        args.iter()
+            .map(|arg| read_value(arg, encoding))
+            .collect::<Result<Vec<_>, _>>()
+    };
+
+    match command {
+        Command::Gen => {
+            let res = client
+                .generate_keys(GenerateKeysRequest {})
+                .await?
+                .into_inner();
+            Ok(Output::default()
+                .bytes("secret_key", res.secret_key)
+                .bytes("public_key", res.public_key))
+        }
+        Command::Sign {
+            secret_key,
+            public_key,
+            message,
+        } => {
+            let req = SignRequest {
+                secret_key: value(secret_key)?,
+                public_key: value(public_key)?,
+                message: value(message)?,
+            };
+            match client.sign(req).await?.into_inner().sig {
+                Some(Sig::Signature(sig)) => {
+                    Ok(Output::default().bytes("signature", sig))
+                }
+                Some(Sig::Error(code)) => Err(service_error(code)),
+                None => Err(empty_response()),
+            }
+        }
+        Command::Verify {
+            apk,
+            signature,
+            message,
+        } => {
+            let req = VerifyRequest {
+                apk: value(apk)?,
+                signature: value(signature)?,
+                message: value(message)?,
+            };
+            match client.verify(req).await?.into_inner().ver {
+                Some(Ver::Valid(valid)) => {
+                    Ok(Output::default().bool("valid", valid))
+                }
+                Some(Ver::Error(code))
+                    if code == Error::BlsVerificationFailed as i32 =>
+                {
+                    Ok(Output::default().bool("valid", false))
+                }
+                Some(Ver::Error(code)) => Err(service_error(code)),
+                None => Err(empty_response()),
+            }
+        }
+        Command::CreateApk { public_key } => {
+            let req = CreateApkRequest {
+                public_key: value(public_key)?,
+            };
+            match client.create_apk(req).await?.into_inner().apk {
+                Some(Apk::Apk(apk)) => Ok(Output::default().bytes("apk", apk)),
+                Some(Apk::Error(code)) => Err(service_error(code)),
+                None => Err(empty_response()),
+            }
+        }
+        Command::AggregatePk { apk, public_keys } => {
+            let req = AggregatePkRequest {
+                apk: value(apk)?,
+                keys: values(public_keys)?,
+            };
+            aggregate_output(
+                client.aggregate_pk(req).await?.into_inner(),
+                "apk",
+            )
+        }
+        Command::AggregateSig {
+            signature,
+            signatures,
+        } => {
+            let req = AggregateSigRequest {
+                signature: value(signature)?,
+                signatures: values(signatures)?,
+            };
+            aggregate_output(
+                client.aggregate_sig(req).await?.into_inner(),
+                "signature",
+            )
+        }
+    }
+}
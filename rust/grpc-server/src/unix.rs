@@ -5,28 +5,57 @@
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
 use std::{
+    fs::{set_permissions, Permissions},
+    io,
+    os::unix::fs::PermissionsExt,
+    path::Path,
     pin::Pin,
     task::{Context, Poll},
 };
 
 use {
     tokio::io::{AsyncRead, AsyncWrite, ReadBuf},
+    tokio::net::unix::UCred,
+    tokio::net::UnixListener,
     tonic::transport::server::Connected,
 };
 
+/// Bind a Unix domain socket at `path` and set its permissions to `mode`, or
+/// to the ones given by the umask if unset.
+///
+/// The socket is created accessible by its owner only, so that no other
+/// client can connect before its permissions are set.
+pub fn bind(path: &Path, mode: Option<u32>) -> io::Result<UnixListener> {
+    // SAFETY: umask never fails. It applies to the whole process, but nothing
+    // else creates files while the service starts up
+    let umask = unsafe { libc::umask(0o177) };
+    let uds = UnixListener::bind(path);
+    unsafe { libc::umask(umask) };
+
+    let uds = uds?;
+    let mode = mode.unwrap_or(0o777 & !(umask as u32));
+    set_permissions(path, Permissions::from_mode(mode))?;
+
+    Ok(uds)
+}
+
 #[derive(Debug)]
 pub struct UnixStream(pub tokio::net::UnixStream);
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct UnixStreamConnectInfo {
-    // Metadata about your connection
+    /// Credentials of the process on the other end of the socket, as given by
+    /// `SO_PEERCRED`, if they could be read
+    pub peer_cred: Option<UCred>,
 }
 
 impl Connected for UnixStream {
     type ConnectInfo = UnixStreamConnectInfo;
 
     fn connect_info(&self) -> Self::ConnectInfo {
-        UnixStreamConnectInfo {}
+        UnixStreamConnectInfo {
+            peer_cred: self.0.peer_cred().ok(),
+        }
     }
 }
 